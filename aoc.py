@@ -32,9 +32,29 @@ log = logging.getLogger("rich")
 URL = f"https://adventofcode.com"
 
 
+def load_config() -> dict:
+    """Settings of aoc.toml, overridden by the user's ~/.config/aoc23/aoc.toml"""
+    try:
+        import tomllib
+    except ModuleNotFoundError:
+        return {}
+
+    config_home = os.environ.get("XDG_CONFIG_HOME", os.path.expanduser("~/.config"))
+    config = {}
+    for path in ["aoc.toml", os.path.join(config_home, "aoc23", "aoc.toml")]:
+        if os.path.exists(path):
+            with open(path, "rb") as f:
+                config.update(tomllib.load(f))
+
+    return config
+
+
+CONFIG = load_config()
+
+
 class AOC:
     URL = f"https://adventofcode.com"
-    DATA = f"data"
+    DATA = CONFIG.get("data_dir", "data")
 
     def __init__(self, year: int, day: int):
         self.year = year
@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use pyo3::{
+    create_exception,
+    exceptions::{PyException, PyNotImplementedError},
+    prelude::*,
+    types::PyDict,
+};
+
+use aoc23::{registry, Answer, Error};
+
+create_exception!(
+    aoc23,
+    ParseError,
+    PyException,
+    "A solution choked on the input it was given, usually while parsing it."
+);
+
+/// Every built-in day as `(year, day, implementations)`, the default
+/// implementation first.
+#[pyfunction]
+fn days() -> Vec<(u16, u8, Vec<&'static str>)> {
+    aoc23::days()
+        .into_iter()
+        .map(|(year, day)| {
+            let names = registry::implementations(year, day)
+                .iter()
+                .map(|e| e.name)
+                .collect();
+            (year, day, names)
+        })
+        .collect()
+}
+
+fn micros(time: Duration) -> u64 {
+    time.as_micros() as u64
+}
+
+fn answer<'py>(py: Python<'py>, answer: &Answer) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("answer", &answer.value)?;
+    dict.set_item("time_us", micros(answer.time))?;
+
+    Ok(dict)
+}
+
+/// Solves both parts of a day on `text`, in the shape of the JSON output:
+/// `parse_time_us`, and `part_1` and `part_2` with their `answer` and
+/// `time_us`.
+#[pyfunction]
+fn solve<'py>(py: Python<'py>, year: u16, day: u8, text: &str) -> PyResult<Bound<'py, PyDict>> {
+    let result = py
+        .allow_threads(|| aoc23::run(year, day, text))
+        .map_err(|e| match e {
+            Error::NotImplemented { .. } => PyNotImplementedError::new_err(e.to_string()),
+            Error::Panicked(_) => ParseError::new_err(e.to_string()),
+        })?;
+
+    let dict = PyDict::new(py);
+    dict.set_item("parse_time_us", micros(result.parse_time))?;
+    dict.set_item("part_1", answer(py, &result.part_1)?)?;
+    dict.set_item("part_2", answer(py, &result.part_2)?)?;
+
+    Ok(dict)
+}
+
+#[pymodule]
+#[pyo3(name = "aoc23")]
+fn aoc23_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(days, m)?)?;
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+    m.add("ParseError", m.py().get_type::<ParseError>())?;
+
+    Ok(())
+}
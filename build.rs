@@ -0,0 +1,61 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+// With the `embed-inputs` feature, lists every `data/aoc<year>/day<N>/*.txt`
+// for `src/embedded.rs` to include in the binary, keyed by its path below
+// `data`. Without it the list is empty.
+fn main() -> io::Result<()> {
+    let mut files = vec![];
+    if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_some() {
+        println!("cargo:rerun-if-changed=data");
+        let data = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("data");
+        if data.is_dir() {
+            collect(&data, &mut files)?;
+        }
+    } else {
+        println!("cargo:rerun-if-changed=build.rs");
+    }
+    files.sort();
+
+    let entries: String = files
+        .iter()
+        .map(|(key, path)| format!("    ({:?}, include_str!({:?})),\n", key, path))
+        .collect();
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("embedded.rs");
+
+    fs::write(
+        out,
+        format!("pub static FILES: &[(&str, &str)] = &[\n{}];\n", entries),
+    )
+}
+
+fn subdirs(dir: &Path, prefix: &str) -> io::Result<Vec<(String, PathBuf)>> {
+    let mut dirs = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        if path.is_dir() && name.starts_with(prefix) {
+            dirs.push((name.to_string(), path));
+        }
+    }
+
+    Ok(dirs)
+}
+
+fn collect(data: &Path, files: &mut Vec<(String, PathBuf)>) -> io::Result<()> {
+    for (year, year_dir) in subdirs(data, "aoc")? {
+        for (day, day_dir) in subdirs(&year_dir, "day")? {
+            for entry in fs::read_dir(&day_dir)? {
+                let path = entry?.path();
+                if path.is_file() && path.extension().is_some_and(|e| e == "txt") {
+                    let name = path.file_name().unwrap_or_default().to_string_lossy();
+                    files.push((format!("{}/{}/{}", year, day, name), path));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,157 @@
+use std::{
+    io,
+    time::{Duration, Instant},
+};
+
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    DefaultTerminal,
+};
+
+use crate::render::Grid;
+
+/// One state of a simulation.
+pub struct Frame {
+    pub label: String,
+    pub grid: Grid,
+}
+
+pub type Frames = Box<dyn Iterator<Item = Frame>>;
+
+const HELP: &str = "space: play/pause  h/l: step  +/-: speed  q: quit";
+
+fn grid_lines(grid: &Grid) -> Vec<Line<'static>> {
+    grid.colours()
+        .iter()
+        .zip(grid.cells().iter())
+        .map(|(colours, cells)| {
+            Line::from(
+                colours
+                    .iter()
+                    .enumerate()
+                    .map(|(i, [r, g, b])| {
+                        let ch = cells.get(i).copied().unwrap_or(' ');
+                        Span::styled(
+                            ch.to_string(),
+                            Style::default().bg(Color::Rgb(*r, *g, *b)).fg(Color::Black),
+                        )
+                    })
+                    .collect::<Vec<Span>>(),
+            )
+        })
+        .collect()
+}
+
+struct Player {
+    frames: Frames,
+    // Every frame seen so far, so stepping back does not rerun the simulation.
+    seen: Vec<Frame>,
+    current: usize,
+    playing: bool,
+    delay: Duration,
+}
+
+impl Player {
+    fn step_forward(&mut self) {
+        if self.current + 1 < self.seen.len() {
+            self.current += 1;
+        } else if let Some(frame) = self.frames.next() {
+            self.seen.push(frame);
+            self.current = self.seen.len() - 1;
+        } else {
+            self.playing = false;
+        }
+    }
+
+    fn step_back(&mut self) {
+        self.current = self.current.saturating_sub(1);
+    }
+
+    fn draw(&self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        let frame = &self.seen[self.current];
+        let status = format!(
+            "frame {} - {}  [{}, {} ms/frame]  {}",
+            self.current + 1,
+            frame.label,
+            if self.playing { "playing" } else { "paused" },
+            self.delay.as_millis(),
+            HELP
+        );
+
+        terminal.draw(|f| {
+            let [grid, status_area] =
+                Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(f.area());
+            f.render_widget(Paragraph::new(grid_lines(&frame.grid)), grid);
+            f.render_widget(
+                Paragraph::new(status).style(Style::default().fg(Color::Yellow)),
+                status_area,
+            );
+        })?;
+
+        Ok(())
+    }
+
+    fn event_loop(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        let mut last_step = Instant::now();
+
+        loop {
+            self.draw(terminal)?;
+
+            let timeout = if self.playing {
+                self.delay.saturating_sub(last_step.elapsed())
+            } else {
+                Duration::from_secs(60)
+            };
+
+            if event::poll(timeout)? {
+                let Event::Key(key) = event::read()? else {
+                    continue;
+                };
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                    KeyCode::Char(' ') => self.playing = !self.playing,
+                    KeyCode::Char('l') | KeyCode::Right => self.step_forward(),
+                    KeyCode::Char('h') | KeyCode::Left => self.step_back(),
+                    KeyCode::Char('+') => {
+                        self.delay = (self.delay / 2).max(Duration::from_millis(10))
+                    }
+                    KeyCode::Char('-') => self.delay = (self.delay * 2).min(Duration::from_secs(5)),
+                    _ => {}
+                }
+            } else if self.playing {
+                self.step_forward();
+                last_step = Instant::now();
+            }
+        }
+    }
+}
+
+/// Replays `frames` in the terminal, starting paused on the first one.
+pub fn play(mut frames: Frames) -> io::Result<()> {
+    let Some(first) = frames.next() else {
+        println!("This day has no animation");
+        return Ok(());
+    };
+
+    let mut player = Player {
+        frames,
+        seen: vec![first],
+        current: 0,
+        playing: false,
+        delay: Duration::from_millis(200),
+    };
+
+    let mut terminal = ratatui::init();
+    let result = player.event_loop(&mut terminal);
+    ratatui::restore();
+
+    result
+}
@@ -1,6 +1,11 @@
-use std::{cell::LazyCell, cmp::min, collections::HashMap, fmt::Display};
+use std::{
+    cell::LazyCell,
+    cmp::min,
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
 
-use crate::Solution;
+use crate::{render::Grid, Assumption, Metadata, Solution};
 
 const DIRS: [[i32; 2]; 4] = [[-1, 0], [0, 1], [1, 0], [0, -1]];
 
@@ -17,14 +22,17 @@ const SYMBOLS: LazyCell<HashMap<char, &'static [u8; 4]>> = LazyCell::new(|| {
     h
 });
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 struct Point(i32, i32);
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Board {
     init: Point,
+    // Number of `S` tiles, `init` being left at (0, 0) when there is none.
+    starts: usize,
     matrix: Vec<Vec<Vec<u8>>>,
-    road_points: Vec<Point>,
 }
 
 impl Display for Board {
@@ -73,19 +81,24 @@ impl Board {
             .to_vec()
     }
 
-    fn is_in_road(&self, point: &Point) -> bool {
-        self.road_points.iter().any(|p| p.eq(point))
-    }
-
-    fn inside_in_line(&self, row: usize, column: usize, up: i32, down: i32, sum: i32) -> i32 {
+    fn inside_in_line(
+        &self,
+        road: &HashSet<Point>,
+        row: usize,
+        column: usize,
+        up: i32,
+        down: i32,
+        sum: i32,
+    ) -> i32 {
         if column == self.matrix[0].len() {
             return sum;
         }
 
         let point = Point(row as i32, column as i32);
-        if self.is_in_road(&point) {
+        if road.contains(&point) {
             let point_dirs = self.get_point_dirs(&point);
             return self.inside_in_line(
+                road,
                 row,
                 column + 1,
                 up + point_dirs[0] as i32,
@@ -93,37 +106,105 @@ impl Board {
                 sum,
             );
         } else if min(up, down) % 2 == 1 {
-            return self.inside_in_line(row, column + 1, up, down, sum + 1);
+            return self.inside_in_line(road, row, column + 1, up, down, sum + 1);
         }
 
-        self.inside_in_line(row, column + 1, up, down, sum)
+        self.inside_in_line(road, row, column + 1, up, down, sum)
     }
 
-    fn find_road(&mut self, point: &Point, dir: usize, len: usize) -> usize {
-        if point.eq(&self.init) && len > 0 {
-            return len;
+    // Same scanline as `inside_in_line`, keeping the tiles instead of counting them.
+    fn enclosed_tiles(&self, road: &HashSet<Point>) -> Vec<Point> {
+        let mut tiles = vec![];
+
+        for (x, line) in self.matrix.iter().enumerate() {
+            let (mut up, mut down) = (0, 0);
+            for (y, dirs) in line.iter().enumerate() {
+                let point = Point(x as i32, y as i32);
+                if road.contains(&point) {
+                    up += dirs[0] as i32;
+                    down += dirs[2] as i32;
+                } else if min(up, down) % 2 == 1 {
+                    tiles.push(point);
+                }
+            }
         }
-        self.road_points.push(*point);
 
-        let next_point = Point(point.0 + DIRS[dir][0], point.1 + DIRS[dir][1]);
-        let next_point_dir = self
-            .get_point_dirs(&next_point)
+        tiles
+    }
+
+    // Area of the loop by the shoelace formula, then the tiles inside it by
+    // Pick's theorem: area = inside + road / 2 - 1.
+    // A loop around no tile has 2 * area = road - 2, hence the signed sum.
+    fn enclosed_by_area(road: &[Point]) -> usize {
+        let twice_area: i64 = road
             .iter()
-            .enumerate()
-            .position(|(i, d)| i != (dir + 2) % 4 && *d == 1)
+            .zip(road.iter().cycle().skip(1))
+            .map(|(a, b)| a.0 as i64 * b.1 as i64 - b.0 as i64 * a.1 as i64)
+            .sum();
+
+        ((twice_area.abs() - road.len() as i64) / 2 + 1) as usize
+    }
+
+    fn get_symbol(&self, point: &Point) -> char {
+        if point.eq(&self.init) {
+            return 'S';
+        }
+
+        match self.get_point_dirs(point)[..] {
+            [1, 0, 1, 0] => '│',
+            [0, 1, 0, 1] => '─',
+            [1, 1, 0, 0] => '└',
+            [1, 0, 0, 1] => '┘',
+            [0, 0, 1, 1] => '┐',
+            [0, 1, 1, 0] => '┌',
+            _ => '.',
+        }
+    }
+
+    // Points of the loop, following it from the start.
+    fn road(&self) -> Vec<Point> {
+        let mut road = vec![];
+        let mut point = self.init;
+        let mut dir = self
+            .get_point_dirs(&self.init)
+            .iter()
+            .position(|d| *d == 1)
             .unwrap();
 
-        self.find_road(&next_point, next_point_dir, len + 1)
+        loop {
+            road.push(point);
+            point = Point(point.0 + DIRS[dir][0], point.1 + DIRS[dir][1]);
+            if point == self.init {
+                return road;
+            }
+
+            dir = self
+                .get_point_dirs(&point)
+                .iter()
+                .enumerate()
+                .position(|(i, d)| i != (dir + 2) % 4 && *d == 1)
+                .unwrap();
+        }
     }
 }
 
 pub struct Day10;
 
+/// Same as `Day10`, counting the enclosed tiles from the area of the loop.
+pub struct Day10Shoelace;
+
 impl Solution for Day10 {
     type ParsedInput = Board;
 
+    const METADATA: Metadata = Metadata {
+        title: "Pipe Maze",
+        tags: &["grid"],
+        algorithm: "walk the loop, then cast rays along each row to find the tiles inside",
+    };
+
     fn parse_input(input_lines: &str) -> Self::ParsedInput {
         let mut init = Point(0, 0);
+        let mut starts = 0;
         let mut tails = vec![];
         let matrix = input_lines
             .lines()
@@ -137,6 +218,7 @@ impl Solution for Day10 {
                         }
                         if c == 'S' {
                             init = Point(x as i32, y as i32);
+                            starts += 1;
                         }
                         SYMBOLS.get(&c).unwrap().to_vec()
                     })
@@ -144,42 +226,135 @@ impl Solution for Day10 {
             })
             .collect();
 
-        Board {
+        // The starting pipe connects to the neighbours that connect to it.
+        let mut board = Board {
             init,
+            starts,
             matrix,
-            road_points: vec![],
-        }
+        };
+        board.update_init_dirs();
+
+        board
     }
 
     fn part_1(parsed_input: &Self::ParsedInput) -> String {
-        let mut part1 = parsed_input.clone();
-        part1.update_init_dirs();
-        let dir = part1
-            .get_point_dirs(&parsed_input.init)
+        (parsed_input.road().len() / 2).to_string()
+    }
+
+    fn part_2(parsed_input: &Self::ParsedInput) -> String {
+        let road: HashSet<Point> = parsed_input.road().into_iter().collect();
+
+        let total = parsed_input
+            .matrix
             .iter()
-            .position(|d| *d == 1)
-            .unwrap();
-        let road_len = part1.find_road(&parsed_input.init, dir, 0);
+            .enumerate()
+            .fold(0, |cur, (x, _)| {
+                cur + parsed_input.inside_in_line(&road, x, 0, 0, 0, 0)
+            });
 
-        (road_len / 2).to_string()
+        total.to_string()
     }
 
-    fn part_2(parsed_input: Self::ParsedInput) -> String {
-        let mut part2 = parsed_input.clone();
-        part2.update_init_dirs();
-        let dir = part2
-            .get_point_dirs(&parsed_input.init)
-            .iter()
-            .position(|d| *d == 1)
-            .unwrap();
-        let _ = part2.find_road(&parsed_input.init, dir, 0);
+    fn assumptions(board: &Self::ParsedInput) -> Vec<Assumption> {
+        let connections = board.get_point_dirs(&board.init).iter().sum::<u8>();
+
+        vec![
+            Assumption {
+                statement: "the input has exactly one start tile",
+                violation: (board.starts != 1).then(|| format!("it has {}", board.starts)),
+            },
+            Assumption {
+                statement: "the start connects to exactly two pipes",
+                violation: (connections != 2)
+                    .then(|| format!("it connects to {} pipes", connections)),
+            },
+        ]
+    }
+
+    fn render(board: &Self::ParsedInput) -> Option<Grid> {
+        let road = board.road();
+        let to_cell = |p: &Point| (p.0 as usize, p.1 as usize);
 
-        let total = part2
+        let cells = board
             .matrix
             .iter()
             .enumerate()
-            .fold(0, |cur, (x, _)| cur + part2.inside_in_line(x, 0, 0, 0, 0));
+            .map(|(x, line)| {
+                (0..line.len())
+                    .map(|y| board.get_symbol(&Point(x as i32, y as i32)))
+                    .collect()
+            })
+            .collect();
 
-        total.to_string()
+        Some(
+            Grid::new(cells)
+                .with_path(road.iter().map(to_cell).collect())
+                .with_highlights(
+                    board
+                        .enclosed_tiles(&road.iter().copied().collect())
+                        .iter()
+                        .map(to_cell)
+                        .collect(),
+                ),
+        )
+    }
+}
+
+impl Solution for Day10Shoelace {
+    type ParsedInput = Board;
+
+    const METADATA: Metadata = Metadata {
+        algorithm: "walk the loop, then count the tiles inside with the shoelace formula and Pick's theorem",
+        ..Day10::METADATA
+    };
+
+    fn parse_input(input_lines: &str) -> Self::ParsedInput {
+        Day10::parse_input(input_lines)
+    }
+
+    fn part_1(parsed_input: &Self::ParsedInput) -> String {
+        Day10::part_1(parsed_input)
+    }
+
+    fn part_2(parsed_input: &Self::ParsedInput) -> String {
+        Board::enclosed_by_area(&parsed_input.road()).to_string()
+    }
+
+    fn assumptions(board: &Self::ParsedInput) -> Vec<Assumption> {
+        Day10::assumptions(board)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    // Both parts of both implementations, which have to agree.
+    fn check(input: &str, part_1: &str, part_2: &str) {
+        check_with::<Day10>("scanline", input, part_1, part_2);
+        check_with::<Day10Shoelace>("shoelace", input, part_1, part_2);
+    }
+
+    fn check_with<S: Solution>(name: &str, input: &str, part_1: &str, part_2: &str) {
+        let run = S::run(input, Part::Both);
+        assert_eq!(run.part_1.unwrap().value, part_1, "{} part 1", name);
+        assert_eq!(run.part_2.unwrap().value, part_2, "{} part 2", name);
+    }
+
+    #[test]
+    fn loop_around_no_tile() {
+        check("S7\nLJ\n", "2", "0");
+        check("S--7\nL--J\n", "4", "0");
+    }
+
+    #[test]
+    fn examples() {
+        check(".....\n.S-7.\n.|.|.\n.L-J.\n.....\n", "4", "1");
+        check(
+            include_str!("../../data/aoc2023/day10/example2.txt"),
+            "70",
+            "8",
+        );
     }
 }
@@ -1,8 +1,9 @@
-use crate::Solution;
+use crate::{render::Grid, Metadata, Solution};
 
 pub struct Day11;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Data {
     galaxies: Vec<(i64, i64)>,
     empty_rows: Vec<i64>,
@@ -45,6 +46,13 @@ impl Data {
 impl Solution for Day11 {
     type ParsedInput = Data;
 
+    const METADATA: Metadata = Metadata {
+        title: "Cosmic Expansion",
+        tags: &["grid", "math"],
+        algorithm:
+            "Manhattan distances, adding the expansion of the empty rows and columns in between",
+    };
+
     fn parse_input(input_lines: &str) -> Self::ParsedInput {
         let mut galaxies = vec![];
         let mut empty_rows = vec![];
@@ -77,7 +85,43 @@ impl Solution for Day11 {
         parsed_input.distance_btwn_galaxies(1).to_string()
     }
 
-    fn part_2(parsed_input: Self::ParsedInput) -> String {
+    fn part_2(parsed_input: &Self::ParsedInput) -> String {
         parsed_input.distance_btwn_galaxies(999_999).to_string()
     }
+
+    fn render(parsed_input: &Self::ParsedInput) -> Option<Grid> {
+        let rows = parsed_input
+            .galaxies
+            .iter()
+            .map(|g| g.0)
+            .chain(parsed_input.empty_rows.iter().copied())
+            .max()? as usize
+            + 1;
+        let columns = parsed_input
+            .galaxies
+            .iter()
+            .map(|g| g.1)
+            .chain(parsed_input.empty_columns.iter().copied())
+            .max()? as usize
+            + 1;
+
+        let mut cells = vec![vec!['.'; columns]; rows];
+        // How many times each cell is expanded: once per empty row or column.
+        let mut heat = vec![vec![0; columns]; rows];
+        for r in parsed_input.empty_rows.iter() {
+            heat[*r as usize].iter_mut().for_each(|h| *h += 1);
+        }
+        for c in parsed_input.empty_columns.iter() {
+            heat.iter_mut().for_each(|row| row[*c as usize] += 1);
+        }
+
+        let galaxies: Vec<(usize, usize)> = parsed_input
+            .galaxies
+            .iter()
+            .map(|g| (g.0 as usize, g.1 as usize))
+            .collect();
+        galaxies.iter().for_each(|g| cells[g.0][g.1] = '#');
+
+        Some(Grid::new(cells).with_heat(heat).with_highlights(galaxies))
+    }
 }
@@ -1,10 +1,14 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    io::{self, BufRead},
+};
 
-use crate::Solution;
+use crate::{parse_lines, Explanation, Metadata, Solution};
 
 pub struct Day12;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 struct Record {
     condition: String,
     groups: Vec<usize>,
@@ -13,6 +17,18 @@ struct Record {
 type Memo = HashMap<(String, Vec<usize>), usize>;
 
 impl Record {
+    fn parse(line: &str) -> Self {
+        let cond_grps = line.split_whitespace().collect::<Vec<&str>>();
+
+        Record {
+            condition: cond_grps[0].to_string(),
+            groups: cond_grps[1]
+                .split(',')
+                .map(|n| n.parse().unwrap())
+                .collect(),
+        }
+    }
+
     fn find_arrangements(&self, cond: &str, groups: &[usize], memo: &mut Memo) -> usize {
         if let Some(memoized) = memo.get(&(cond.to_string(), groups.to_vec())) {
             return *memoized;
@@ -64,6 +80,7 @@ impl Record {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Records(Vec<Record>);
 
 impl Records {
@@ -100,30 +117,53 @@ impl Records {
 impl Solution for Day12 {
     type ParsedInput = Records;
 
+    const METADATA: Metadata = Metadata {
+        title: "Hot Springs",
+        tags: &["dynamic-programming"],
+        algorithm: "memoised recursion over the springs and the remaining groups",
+    };
+
     fn parse_input(input_lines: &str) -> Self::ParsedInput {
-        Records(
-            input_lines
-                .lines()
-                .map(|line| {
-                    let cond_grps = line.split_whitespace().collect::<Vec<&str>>();
-
-                    Record {
-                        condition: cond_grps[0].to_string(),
-                        groups: cond_grps[1]
-                            .split(',')
-                            .map(|n| n.parse().unwrap())
-                            .collect(),
-                    }
-                })
-                .collect(),
-        )
+        Records(input_lines.lines().map(Record::parse).collect())
+    }
+
+    fn parse_reader(reader: &mut dyn BufRead) -> io::Result<Self::ParsedInput> {
+        Ok(Records(parse_lines(reader, Record::parse)?))
     }
 
     fn part_1(parsed_input: &Self::ParsedInput) -> String {
         parsed_input.sum_of_arrangements().to_string()
     }
 
-    fn part_2(parsed_input: Self::ParsedInput) -> String {
+    fn part_2(parsed_input: &Self::ParsedInput) -> String {
         parsed_input.sum_by_five().to_string()
     }
+
+    fn explain(parsed_input: &Self::ParsedInput, limit: usize) -> Vec<Explanation> {
+        parsed_input
+            .0
+            .iter()
+            .take(limit)
+            .map(|r| {
+                let unfolded = parsed_input.extend(r);
+                Explanation {
+                    item: format!("Record {} {:?}", r.condition, r.groups),
+                    details: vec![
+                        format!(
+                            "arrangements: {}",
+                            r.find_arrangements(&r.condition, &r.groups, &mut Memo::new())
+                        ),
+                        format!(
+                            "unfolded arrangements: {}",
+                            unfolded.find_arrangements(
+                                &unfolded.condition,
+                                &unfolded.groups,
+                                &mut Memo::new()
+                            )
+                        ),
+                    ],
+                }
+            })
+            .collect()
+    }
 }
@@ -1,11 +1,12 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use crate::Solution;
+use crate::{Metadata, Solution};
 
 pub struct Day13;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 struct Matrix {
     rows: Vec<String>,
     columns: Vec<String>,
@@ -121,6 +122,7 @@ impl Matrix {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Data(Vec<Matrix>);
 
 impl Data {
@@ -156,6 +158,12 @@ impl Data {
 impl Solution for Day13 {
     type ParsedInput = Data;
 
+    const METADATA: Metadata = Metadata {
+        title: "Point of Incidence",
+        tags: &["grid"],
+        algorithm: "compare rows and columns outwards from each candidate line, allowing one smudge in part 2",
+    };
+
     fn parse_input(input_lines: &str) -> Self::ParsedInput {
         let mut rows: Vec<String> = vec![];
         let mut columns: Vec<String> = vec![];
@@ -189,7 +197,7 @@ impl Solution for Day13 {
         parsed_input.summarize().to_string()
     }
 
-    fn part_2(parsed_input: Self::ParsedInput) -> String {
+    fn part_2(parsed_input: &Self::ParsedInput) -> String {
         parsed_input.summarize_with_smudge().to_string()
     }
 }
@@ -1,13 +1,24 @@
-use std::{collections::HashMap, fmt::Display};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    iter,
+};
 
-use crate::Solution;
+use crate::{
+    animation::{Frame, Frames},
+    progress,
+    render::Grid,
+    square_grid, Assumption, Metadata, SolutionMut,
+};
 
 pub struct Day14;
 
 #[derive(Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 struct Platform(Vec<Vec<char>>);
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Matrix {
     matrix: Platform,
 }
@@ -105,6 +116,23 @@ impl Matrix {
         }
     }
 
+    fn to_grid(&self) -> Grid {
+        let rocks = self
+            .matrix
+            .0
+            .iter()
+            .enumerate()
+            .flat_map(|(x, line)| {
+                line.iter()
+                    .enumerate()
+                    .filter(|(_, ch)| **ch == 'O')
+                    .map(move |(y, _)| (x, y))
+            })
+            .collect();
+
+        Grid::new(self.matrix.0.clone()).with_highlights(rocks)
+    }
+
     fn load_on_north(&self) -> usize {
         let mut current_val = self.matrix.0.len();
 
@@ -117,9 +145,15 @@ impl Matrix {
     }
 }
 
-impl Solution for Day14 {
+impl SolutionMut for Day14 {
     type ParsedInput = Matrix;
 
+    const METADATA: Metadata = Metadata {
+        title: "Parabolic Reflector Dish",
+        tags: &["grid", "cycle-detection", "simulation"],
+        algorithm: "tilt the platform, and skip ahead once a spin cycle repeats",
+    };
+
     fn parse_input(input_lines: &str) -> Self::ParsedInput {
         let mut matrix = Vec::new();
 
@@ -136,18 +170,19 @@ impl Solution for Day14 {
         }
     }
 
-    fn part_1(parsed_input: &Self::ParsedInput) -> String {
-        let mut cloned = parsed_input.clone();
-        cloned.tilt(DIRECTION::NORTH);
-        cloned.load_on_north().to_string()
+    fn part_1(parsed_input: &mut Self::ParsedInput) -> String {
+        parsed_input.tilt(DIRECTION::NORTH);
+        parsed_input.load_on_north().to_string()
     }
 
-    fn part_2(mut parsed_input: Self::ParsedInput) -> String {
-        let mut times = 1_000_000_000;
+    fn part_2(parsed_input: &mut Self::ParsedInput) -> String {
+        const CYCLES: usize = 1_000_000_000;
+        let mut times = CYCLES;
         let mut is_counting = false;
         let mut count = 1;
         let mut memo: HashMap<Vec<Vec<char>>, usize> = HashMap::new();
 
+        progress::start("spin cycles", CYCLES as u64);
         while times > 0 {
             SPIN_CYCLE.iter().for_each(|dir| {
                 parsed_input.tilt(*dir);
@@ -172,8 +207,67 @@ impl Solution for Day14 {
             });
 
             times -= 1;
+            progress::set((CYCLES - times) as u64);
         }
+        progress::finish();
 
         parsed_input.load_on_north().to_string()
     }
+
+    fn render(parsed_input: &Self::ParsedInput) -> Option<Grid> {
+        let mut tilted = parsed_input.clone();
+        tilted.tilt(DIRECTION::NORTH);
+
+        Some(tilted.to_grid())
+    }
+
+    fn assumptions(parsed_input: &Self::ParsedInput) -> Vec<Assumption> {
+        vec![square_grid(
+            "the platform is square",
+            &parsed_input.matrix.0,
+        )]
+    }
+
+    // Every tilt of the spin cycles, until a whole cycle repeats an earlier one.
+    fn frames(parsed_input: Self::ParsedInput) -> Frames {
+        let start = Frame {
+            label: format!("start, load {}", parsed_input.load_on_north()),
+            grid: parsed_input.to_grid(),
+        };
+
+        let mut matrix = parsed_input;
+        let mut seen: HashSet<Vec<Vec<char>>> = HashSet::new();
+        let mut tilts = 0;
+        let mut repeated = false;
+
+        Box::new(iter::once(start).chain(iter::from_fn(move || {
+            if repeated {
+                return None;
+            }
+
+            let dir = SPIN_CYCLE[tilts % SPIN_CYCLE.len()];
+            matrix.tilt(dir);
+            tilts += 1;
+            if tilts % SPIN_CYCLE.len() == 0 && !seen.insert(matrix.matrix.0.clone()) {
+                repeated = true;
+            }
+
+            let note = if repeated {
+                " (repeats an earlier cycle)"
+            } else {
+                ""
+            };
+
+            Some(Frame {
+                label: format!(
+                    "cycle {}, tilt {:?}, load {}{}",
+                    (tilts - 1) / SPIN_CYCLE.len() + 1,
+                    dir,
+                    matrix.load_on_north(),
+                    note
+                ),
+                grid: matrix.to_grid(),
+            })
+        })))
+    }
 }
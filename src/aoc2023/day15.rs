@@ -1,16 +1,18 @@
 use std::collections::HashMap;
 
-use crate::Solution;
+use crate::{Metadata, Solution};
 
 pub struct Day15;
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 enum OP {
     ADD,
     REMOVE,
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 struct Step {
     def: String,
     label: String,
@@ -42,6 +44,7 @@ impl Step {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Steps(Vec<Step>);
 
 impl Steps {
@@ -55,6 +58,12 @@ type Box = Vec<(String, Option<usize>)>;
 impl Solution for Day15 {
     type ParsedInput = Steps;
 
+    const METADATA: Metadata = Metadata {
+        title: "Lens Library",
+        tags: &["hashing", "simulation"],
+        algorithm: "run the HASH algorithm and keep the lenses of each box in order",
+    };
+
     fn parse_input(mut input_lines: &str) -> Self::ParsedInput {
         input_lines = input_lines.trim();
         Steps(
@@ -86,7 +95,7 @@ impl Solution for Day15 {
         parsed_input.get_total_sum().to_string()
     }
 
-    fn part_2(parsed_input: Self::ParsedInput) -> String {
+    fn part_2(parsed_input: &Self::ParsedInput) -> String {
         let mut boxes: HashMap<usize, Box> = HashMap::new();
         parsed_input.0.iter().for_each(|step| {
             let op = &step.operation;
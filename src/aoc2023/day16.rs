@@ -1,8 +1,16 @@
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    iter,
+};
 
-use crate::Solution;
+use crate::{
+    animation::{Frame, Frames},
+    progress,
+    render::Grid,
+    Metadata, Solution,
+};
 
-#[derive(PartialEq, Copy, Clone, Debug)]
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
 enum DIR {
     UP,
     DOWN,
@@ -21,7 +29,8 @@ impl DIR {
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 enum SYMBOL {
     POINT,
     SLASH,
@@ -30,6 +39,8 @@ enum SYMBOL {
     BAR,
 }
 
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Matrix(Vec<Vec<SYMBOL>>);
 
 type Memo = HashMap<(isize, isize), DIR>;
@@ -75,6 +86,25 @@ impl Matrix {
         )
     }
 
+    fn to_char(&self, symbol: SYMBOL) -> char {
+        match symbol {
+            SYMBOL::POINT => '.',
+            SYMBOL::SLASH => '/',
+            SYMBOL::SLASHB => '\\',
+            SYMBOL::DASH => '-',
+            SYMBOL::BAR => '|',
+        }
+    }
+
+    fn to_grid(&self) -> Grid {
+        Grid::new(
+            self.0
+                .iter()
+                .map(|line| line.iter().map(|s| self.to_char(*s)).collect())
+                .collect(),
+        )
+    }
+
     fn energized_tiles(&self, dir: DIR, point: &(isize, isize), memo: &mut Memo) -> usize {
         if self.is_out_of_bounds(point) {
             return 0;
@@ -118,6 +148,12 @@ pub struct Day16;
 impl Solution for Day16 {
     type ParsedInput = Matrix;
 
+    const METADATA: Metadata = Metadata {
+        title: "The Floor Will Be Lava",
+        tags: &["grid", "simulation"],
+        algorithm: "trace the beams, remembering the direction each tile was entered from",
+    };
+
     fn parse_input(input_lines: &str) -> Self::ParsedInput {
         Matrix(
             input_lines
@@ -144,15 +180,23 @@ impl Solution for Day16 {
             .to_string()
     }
 
-    fn part_2(parsed_input: Self::ParsedInput) -> String {
-        parsed_input
+    fn part_2(parsed_input: &Self::ParsedInput) -> String {
+        progress::start(
+            "tiles",
+            parsed_input.0.iter().map(|line| line.len() as u64).sum(),
+        );
+
+        let mut best = 0;
+        let max = parsed_input
             .0
             .iter()
             .enumerate()
             .map(|(x, line)| {
-                line.iter()
+                let max_of_line = line
+                    .iter()
                     .enumerate()
                     .map(|(y, _)| {
+                        progress::advance(1);
                         if x == 0 && y == 0 {
                             let res1 =
                                 parsed_input.energized_tiles_with_config(DIR::RIGHT, &(x, y));
@@ -185,12 +229,74 @@ impl Solution for Day16 {
                     })
                     .collect::<Vec<usize>>()
                     .iter()
-                    .fold(0, |acc, i| if *i > acc { *i } else { acc })
+                    .fold(0, |acc, i| if *i > acc { *i } else { acc });
+
+                best = best.max(max_of_line);
+                progress::best(best);
+                max_of_line
             })
             .collect::<Vec<_>>()
             .iter()
-            .fold(0, |acc, i| if *i > acc { *i } else { acc })
-            .to_string()
+            .fold(0, |acc, i| if *i > acc { *i } else { acc });
+
+        progress::finish();
+        max.to_string()
+    }
+
+    fn render(parsed_input: &Self::ParsedInput) -> Option<Grid> {
+        let mut memo = HashMap::new();
+        parsed_input.energized_tiles(DIR::RIGHT, &(0, 0), &mut memo);
+
+        Some(
+            parsed_input
+                .to_grid()
+                .with_highlights(memo.keys().map(|p| (p.0 as usize, p.1 as usize)).collect()),
+        )
+    }
+
+    // The beam of part 1 moving one tile per frame, splitting as it goes.
+    fn frames(parsed_input: Self::ParsedInput) -> Frames {
+        let matrix = parsed_input;
+        let mut beams: Vec<(DIR, (isize, isize))> = vec![(DIR::RIGHT, (0, 0))];
+        let mut visited: HashSet<(DIR, (isize, isize))> = beams.iter().copied().collect();
+        let mut energized: HashSet<(isize, isize)> = HashSet::new();
+        let mut step = 0;
+
+        Box::new(iter::from_fn(move || {
+            if beams.is_empty() {
+                return None;
+            }
+
+            energized.extend(beams.iter().map(|(_, point)| *point));
+            let to_cell = |p: &(isize, isize)| (p.0 as usize, p.1 as usize);
+            let frame = Frame {
+                label: format!(
+                    "step {}, {} beams, {} energized",
+                    step,
+                    beams.len(),
+                    energized.len()
+                ),
+                // The heads of the beams are drawn as the path.
+                grid: matrix
+                    .to_grid()
+                    .with_highlights(energized.iter().map(to_cell).collect())
+                    .with_path(beams.iter().map(|(_, p)| to_cell(p)).collect()),
+            };
+
+            beams = beams
+                .iter()
+                .flat_map(|(dir, point)| {
+                    matrix
+                        .next_dir(*dir, point.0, point.1)
+                        .into_iter()
+                        .map(move |d| (d, (point.0 + d.values().0, point.1 + d.values().1)))
+                })
+                .filter(|beam| !matrix.is_out_of_bounds(&beam.1) && visited.insert(*beam))
+                .collect();
+            step += 1;
+
+            Some(frame)
+        }))
     }
 }
 
@@ -6,7 +6,7 @@ use std::{
     fmt::Display,
 };
 
-use crate::Solution;
+use crate::{render::Grid, square_grid, Assumption, Metadata, Solution};
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 enum Dir {
@@ -85,6 +85,7 @@ impl Display for Path {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Matrix(Vec<Vec<usize>>);
 
 impl Matrix {
@@ -95,7 +96,8 @@ impl Matrix {
             || point.1 as usize > self.0.len() - 1
     }
 
-    fn dijkstra(&self, goal: (isize, isize)) -> Option<usize> {
+    // Least heat loss to `goal`, and the path taken from the top-left corner.
+    fn dijkstra(&self, goal: (isize, isize)) -> Option<(usize, Vec<(isize, isize)>)> {
         let mut current_heat: Vec<Vec<_>> = (0..self.0.len())
             .map(|_| (0..self.0.len()).map(|_| usize::MAX).collect())
             .collect();
@@ -124,12 +126,9 @@ impl Matrix {
                     }
                 }
                 path.push((0, 0));
+                path.reverse();
 
-                for p in path.iter().rev() {
-                    eprintln!("{p:?}");
-                }
-
-                return Some(heat);
+                return Some((heat, path));
             }
 
             // hay otro camino mejor
@@ -227,6 +226,12 @@ pub struct Day17;
 impl Solution for Day17 {
     type ParsedInput = Matrix;
 
+    const METADATA: Metadata = Metadata {
+        title: "Clumsy Crucible",
+        tags: &["grid", "shortest-path"],
+        algorithm: "Dijkstra over position, direction and steps taken in a straight line",
+    };
+
     fn parse_input(input_lines: &str) -> Self::ParsedInput {
         Matrix(
             input_lines
@@ -241,7 +246,7 @@ impl Solution for Day17 {
     }
 
     fn part_1(parsed_input: &Self::ParsedInput) -> String {
-        if let Some(res) = parsed_input.dijkstra((
+        if let Some((res, _)) = parsed_input.dijkstra((
             (parsed_input.0.len() - 1) as isize,
             (parsed_input.0.len() - 1) as isize,
         )) {
@@ -251,7 +256,35 @@ impl Solution for Day17 {
         String::from("None")
     }
 
-    fn part_2(parsed_input: Self::ParsedInput) -> String {
+    fn part_2(parsed_input: &Self::ParsedInput) -> String {
         "".to_string()
     }
+
+    fn assumptions(parsed_input: &Self::ParsedInput) -> Vec<Assumption> {
+        vec![square_grid("the city is square", &parsed_input.0)]
+    }
+
+    fn render(parsed_input: &Self::ParsedInput) -> Option<Grid> {
+        let last = (parsed_input.0.len() - 1) as isize;
+        let path = match parsed_input.dijkstra((last, last)) {
+            Some((_, path)) => path.iter().map(|p| (p.0 as usize, p.1 as usize)).collect(),
+            None => vec![],
+        };
+
+        let cells = parsed_input
+            .0
+            .iter()
+            .map(|line| {
+                line.iter()
+                    .map(|h| char::from_digit(*h as u32, 10).unwrap())
+                    .collect()
+            })
+            .collect();
+
+        Some(
+            Grid::new(cells)
+                .with_heat(parsed_input.0.clone())
+                .with_path(path),
+        )
+    }
 }
@@ -1,4 +1,4 @@
-use crate::Solution;
+use crate::{Metadata, Solution};
 use std::cmp::{max, min};
 
 pub struct Day3;
@@ -16,6 +16,7 @@ struct Gear {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Grid(Vec<Vec<char>>);
 
 impl Grid {
@@ -223,6 +224,13 @@ impl Grid {
 impl Solution for Day3 {
     type ParsedInput = Grid;
 
+    const METADATA: Metadata = Metadata {
+        title: "Gear Ratios",
+        tags: &["grid", "parsing"],
+        algorithm:
+            "collect the numbers touching a symbol, then the pairs of numbers around each gear",
+    };
+
     fn parse_input(input_lines: &str) -> Self::ParsedInput {
         let data = input_lines.lines().map(|l| l.chars().collect()).collect();
 
@@ -233,7 +241,7 @@ impl Solution for Day3 {
         format!("{}", parsed_input.get_part_numbers().iter().sum::<usize>())
     }
 
-    fn part_2(parsed_input: Self::ParsedInput) -> String {
+    fn part_2(parsed_input: &Self::ParsedInput) -> String {
         format!("{}", parsed_input.get_ratios().iter().sum::<usize>())
     }
 }
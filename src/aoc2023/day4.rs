@@ -1,14 +1,33 @@
-use crate::Solution;
+use std::io::{self, BufRead};
+
+use crate::{parse_lines, Explanation, Metadata, Solution};
 
 pub struct Day4;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Card {
     winning: Vec<String>,
     playing: Vec<String>,
 }
 
 impl Card {
+    fn parse(line: &str) -> Self {
+        let numbers: Vec<&str> = line.split([':']).nth(1).unwrap().split('|').collect();
+        Card {
+            winning: numbers[0]
+                .trim()
+                .split_whitespace()
+                .map(|i| i.to_string())
+                .collect::<Vec<String>>(),
+            playing: numbers[1]
+                .trim()
+                .split_whitespace()
+                .map(|i| i.to_string())
+                .collect::<Vec<String>>(),
+        }
+    }
+
     fn get_matches(&self) -> usize {
         self.winning.iter().fold(0, |cur, n| {
             if self.playing.contains(n) {
@@ -31,6 +50,7 @@ impl Card {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Table(Vec<Card>);
 
 impl Table {
@@ -42,7 +62,7 @@ impl Table {
             .to_string()
     }
 
-    fn get_all_instances(&self) -> String {
+    fn get_instances(&self) -> Vec<usize> {
         let mut instances = vec![1; self.0.len()];
 
         for (i, card) in self.0.iter().enumerate() {
@@ -52,42 +72,78 @@ impl Table {
             }
         }
 
-        instances.iter().sum::<usize>().to_string()
+        instances
+    }
+
+    fn get_all_instances(&self) -> String {
+        self.get_instances().iter().sum::<usize>().to_string()
     }
 }
 
 impl Solution for Day4 {
     type ParsedInput = Table;
 
+    const METADATA: Metadata = Metadata {
+        title: "Scratchcards",
+        tags: &["dynamic-programming"],
+        algorithm: "count the matches, then carry the copies won forward card by card",
+    };
+
     fn parse_input(input_lines: &str) -> Self::ParsedInput {
-        let mut cards: Vec<Card> = vec![];
-
-        for line in input_lines.lines() {
-            let numbers: Vec<&str> = line.split([':']).nth(1).unwrap().split('|').collect();
-            let c = Card {
-                winning: numbers[0]
-                    .trim()
-                    .split_whitespace()
-                    .map(|i| i.to_string())
-                    .collect::<Vec<String>>(),
-                playing: numbers[1]
-                    .trim()
-                    .split_whitespace()
-                    .map(|i| i.to_string())
-                    .collect::<Vec<String>>(),
-            };
-            cards.push(c);
-        }
+        Table(input_lines.lines().map(Card::parse).collect())
+    }
 
-        Table(cards)
+    fn parse_reader(reader: &mut dyn BufRead) -> io::Result<Self::ParsedInput> {
+        Ok(Table(parse_lines(reader, Card::parse)?))
     }
 
     fn part_1(parsed_input: &Self::ParsedInput) -> String {
         parsed_input.get_all_points()
     }
 
-    fn part_2(parsed_input: Self::ParsedInput) -> String {
+    fn part_2(parsed_input: &Self::ParsedInput) -> String {
         parsed_input.get_all_instances()
     }
+
+    fn explain(parsed_input: &Self::ParsedInput, limit: usize) -> Vec<Explanation> {
+        let instances = parsed_input.get_instances();
+
+        parsed_input
+            .0
+            .iter()
+            .enumerate()
+            .take(limit)
+            .map(|(i, card)| {
+                let matches = card.get_matches();
+                let mut details = vec![
+                    format!(
+                        "matches: {} ({})",
+                        matches,
+                        card.winning
+                            .iter()
+                            .filter(|n| card.playing.contains(n))
+                            .cloned()
+                            .collect::<Vec<String>>()
+                            .join(" ")
+                    ),
+                    format!("points: {}", card.get_points()),
+                    format!("instances: {}", instances[i]),
+                ];
+                if matches > 0 {
+                    details.push(format!(
+                        "wins {} copies of cards {}..={}",
+                        instances[i],
+                        i + 2,
+                        i + 1 + matches
+                    ));
+                }
+
+                Explanation {
+                    item: format!("Card {}", i + 1),
+                    details,
+                }
+            })
+            .collect()
+    }
 }
 
@@ -1,10 +1,14 @@
 use std::usize::MAX;
 
-use crate::Solution;
+use crate::{progress, Assumption, Explanation, Metadata, Solution};
 
 pub struct Day5;
 
+/// Same as `Day5`, mapping seed ranges as intervals in part 2.
+pub struct Day5Intervals;
+
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Almanac {
     initial_numbers: Vec<usize>,
     pairs: Vec<(usize, usize)>,
@@ -43,17 +47,44 @@ impl Almanac {
     }
 
     fn get_lowest_location_from_pairs(&self) -> String {
-        self.pairs
+        progress::start("seeds", self.pairs.iter().map(|p| p.1 as u64).sum());
+
+        let lowest = self.pairs.iter().fold(MAX, |min, pair| {
+            let min_of_pair = self.get_min_location_of_pair(*pair);
+
+            if min_of_pair < min {
+                progress::best(min_of_pair);
+                return min_of_pair;
+            }
+
+            min
+        });
+
+        progress::finish();
+        lowest.to_string()
+    }
+
+    // Maps whole seed ranges at once, splitting them wherever a map line starts
+    // or ends, instead of stepping through the seeds.
+    fn get_lowest_location_from_intervals(&self) -> String {
+        let mut ranges: Vec<(usize, usize)> = self
+            .pairs
             .iter()
-            .fold(MAX, |min, pair| {
-                let min_of_pair = self.get_min_location_of_pair(*pair);
+            .map(|(start, len)| (*start, start + len))
+            .collect();
 
-                if min_of_pair < min {
-                    return min_of_pair;
-                }
+        for map in self.maps.iter() {
+            ranges = ranges
+                .iter()
+                .flat_map(|r| map.get_mapped_range(*r))
+                .collect();
+        }
 
-                min
-            })
+        ranges
+            .iter()
+            .map(|(start, _)| *start)
+            .min()
+            .unwrap_or(usize::MAX)
             .to_string()
     }
 
@@ -67,12 +98,31 @@ impl Almanac {
 
             min = if result < min { result } else { min };
 
-            current_value += if distance == MAX { 1 } else { distance };
+            let step = if distance == MAX { 1 } else { distance };
+            progress::advance(step.min(pair.0 + pair.1 - current_value) as u64);
+            current_value += step;
         }
 
         min
     }
 
+    // The maps are folded in input order, so each one has to read what the
+    // previous one produced.
+    fn map_order_violation(&self) -> Option<String> {
+        let mut category = "seed";
+        for m in self.maps.iter() {
+            if m.source != category {
+                return Some(format!(
+                    "{}-to-{} follows a map to {}",
+                    m.source, m.category, category
+                ));
+            }
+            category = &m.category;
+        }
+
+        (category != "location").then(|| format!("the last map leads to {}", category))
+    }
+
     fn get_location(&self, number: usize) -> usize {
         self.maps.iter().fold(number, |n, m| m.get_mapped(n))
     }
@@ -94,13 +144,21 @@ impl Almanac {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 struct Map {
+    // What the map converts from and to, e.g. "seed" and "soil" for "seed-to-soil"
+    source: String,
+    category: String,
     lines: Vec<Line>,
 }
 
 impl Map {
     fn new() -> Self {
-        Self { lines: vec![] }
+        Self {
+            source: String::new(),
+            category: String::new(),
+            lines: vec![],
+        }
     }
 
     fn get_mapped(&self, number: usize) -> usize {
@@ -111,6 +169,38 @@ impl Map {
         }
     }
 
+    // Images of the half-open range [start, end) under this map.
+    fn get_mapped_range(&self, (start, end): (usize, usize)) -> Vec<(usize, usize)> {
+        let mut mapped = vec![];
+        let mut unmapped = vec![(start, end)];
+
+        for l in self.lines.iter() {
+            let mut rest = vec![];
+            for (start, end) in unmapped {
+                let (from, to) = (start.max(l.source), end.min(l.source + l.range));
+                if from >= to {
+                    rest.push((start, end));
+                    continue;
+                }
+
+                mapped.push((
+                    l.destination + from - l.source,
+                    l.destination + to - l.source,
+                ));
+                if start < from {
+                    rest.push((start, from));
+                }
+                if to < end {
+                    rest.push((to, end));
+                }
+            }
+            unmapped = rest;
+        }
+
+        mapped.extend(unmapped);
+        mapped
+    }
+
     fn get_line_and_index(&self, number: usize) -> Option<(usize, usize)> {
         for (i, l) in self.lines.iter().enumerate() {
             if let Some(index) = l.index_of_value(number) {
@@ -141,6 +231,7 @@ impl Map {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 struct Line {
     destination: usize,
     source: usize,
@@ -160,6 +251,12 @@ impl Line {
 impl Solution for Day5 {
     type ParsedInput = Almanac;
 
+    const METADATA: Metadata = Metadata {
+        title: "If You Give A Seed A Fertilizer",
+        tags: &["intervals"],
+        algorithm: "map every seed through the maps, skipping ahead to the next range boundary",
+    };
+
     fn parse_input(input_lines: &str) -> Self::ParsedInput {
         let mut almanac = Almanac::new();
         let mut mapping = false;
@@ -172,6 +269,12 @@ impl Solution for Day5 {
                     almanac.maps.push(map.clone());
                     map = Map::new();
                 }
+
+                if let Some(name) = line.strip_suffix(" map:") {
+                    let (source, category) = name.split_once("-to-").unwrap_or((name, name));
+                    map.source = source.to_string();
+                    map.category = category.to_string();
+                }
                 continue;
             }
 
@@ -215,7 +318,70 @@ impl Solution for Day5 {
         almanac.get_lowest_location()
     }
 
-    fn part_2(almanac: Self::ParsedInput) -> String {
+    fn part_2(almanac: &Self::ParsedInput) -> String {
         almanac.get_lowest_location_from_pairs()
     }
+
+    fn explain(almanac: &Self::ParsedInput, limit: usize) -> Vec<Explanation> {
+        almanac
+            .initial_numbers
+            .iter()
+            .take(limit)
+            .map(|seed| {
+                let mut number = *seed;
+                let details = almanac
+                    .maps
+                    .iter()
+                    .map(|m| {
+                        let mapped = m.get_mapped(number);
+                        let detail = match m.get_line_and_index(number) {
+                            Some((line, _)) => {
+                                format!("{} {} (range {})", m.category, mapped, line + 1)
+                            }
+                            None => format!("{} {} (unmapped)", m.category, mapped),
+                        };
+                        number = mapped;
+                        detail
+                    })
+                    .collect();
+
+                Explanation {
+                    item: format!("Seed {} -> location {}", seed, almanac.get_location(*seed)),
+                    details,
+                }
+            })
+            .collect()
+    }
+
+    fn assumptions(almanac: &Self::ParsedInput) -> Vec<Assumption> {
+        vec![Assumption {
+            statement: "the maps lead from seed to location in the order they are given",
+            violation: almanac.map_order_violation(),
+        }]
+    }
+}
+
+impl Solution for Day5Intervals {
+    type ParsedInput = Almanac;
+
+    const METADATA: Metadata = Metadata {
+        algorithm: "split the seed ranges at the boundaries of each map",
+        ..Day5::METADATA
+    };
+
+    fn parse_input(input_lines: &str) -> Self::ParsedInput {
+        Day5::parse_input(input_lines)
+    }
+
+    fn part_1(almanac: &Self::ParsedInput) -> String {
+        Day5::part_1(almanac)
+    }
+
+    fn part_2(almanac: &Self::ParsedInput) -> String {
+        almanac.get_lowest_location_from_intervals()
+    }
+
+    fn assumptions(almanac: &Self::ParsedInput) -> Vec<Assumption> {
+        Day5::assumptions(almanac)
+    }
 }
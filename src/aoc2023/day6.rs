@@ -1,7 +1,9 @@
-use crate::Solution;
+use crate::{Metadata, Solution};
 
 pub struct Day6;
 
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Paper(Vec<Race>);
 
 impl Paper {
@@ -33,6 +35,7 @@ impl Paper {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 struct Race {
     time: usize,
     record: usize,
@@ -55,6 +58,12 @@ impl Race {
 impl Solution for Day6 {
     type ParsedInput = Paper;
 
+    const METADATA: Metadata = Metadata {
+        title: "Wait For It",
+        tags: &["math"],
+        algorithm: "solve the quadratic for the shortest winning hold",
+    };
+
     fn parse_input(input_lines: &str) -> Self::ParsedInput {
         let mut lines = input_lines.lines();
         let times_str: &str = lines.next().unwrap();
@@ -90,7 +99,7 @@ impl Solution for Day6 {
         parsed_input.ways_to_beat_record()
     }
 
-    fn part_2(parsed_input: Self::ParsedInput) -> String {
+    fn part_2(parsed_input: &Self::ParsedInput) -> String {
         parsed_input.ways_to_beat_race()
     }
 }
@@ -1,12 +1,16 @@
-use crate::Solution;
+use crate::{parse_lines, Explanation, Metadata, SolutionMut};
 
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    io::{self, BufRead},
+};
 
 pub struct Day7;
 
 const CARDS: [char; 5] = ['T', 'J', 'Q', 'K', 'A'];
 
 #[derive(Debug, Eq, Hash, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 enum Type {
     HighCard,
     OnePair,
@@ -35,6 +39,7 @@ enum Compare {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 struct Line {
     hand: String,
     hand_type: Type,
@@ -50,6 +55,16 @@ impl Line {
         }
     }
 
+    fn parse(line: &str) -> Self {
+        let mut items = line.split_whitespace();
+        let hand = items.next().unwrap().to_string();
+        Line::new(
+            hand.clone(),
+            get_hand_type(hand.clone()),
+            items.next().unwrap().parse().unwrap(),
+        )
+    }
+
     fn compare_hand(&self, other_hand: String, with_jokers: bool) -> Compare {
         let other_hand_chars: Vec<char> = other_hand.chars().collect();
         let self_hand_chars: Vec<char> = self.hand.chars().collect();
@@ -151,6 +166,7 @@ impl Line {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Game {
     types: HashMap<Type, Vec<Line>>,
     lines: Vec<Line>,
@@ -164,10 +180,32 @@ impl Game {
         }
     }
 
-    fn get_total_winnings(&mut self, with_jokers: bool) -> String {
+    fn use_jokers(&mut self) {
+        for line in self.lines.iter_mut() {
+            if line.hand.contains('J') {
+                line.hand_type = line.get_hand_type_with_jokers();
+            }
+        }
+    }
+
+    // Rank of every hand, in the order of the input.
+    fn get_ranks(&mut self, with_jokers: bool) -> Vec<usize> {
         self.sort_hands(with_jokers);
 
-        // println!("{:#?}", self.types);
+        let ordered: Vec<&Line> = ORDERED_TYPES
+            .iter()
+            .filter_map(|t| self.types.get(t))
+            .flatten()
+            .collect();
+
+        self.lines
+            .iter()
+            .map(|line| ordered.iter().position(|l| l.hand == line.hand).unwrap() + 1)
+            .collect()
+    }
+
+    fn get_total_winnings(&mut self, with_jokers: bool) -> String {
+        self.sort_hands(with_jokers);
 
         let mut factor = 1;
         let mut total = 0;
@@ -265,39 +303,60 @@ fn get_hand_type(hand: String) -> Type {
     }
 }
 
-impl Solution for Day7 {
+impl SolutionMut for Day7 {
     type ParsedInput = Game;
 
+    const METADATA: Metadata = Metadata {
+        title: "Camel Cards",
+        tags: &["sorting"],
+        algorithm: "rank the hands by type, then card by card, with jokers taking the best type",
+    };
+
     fn parse_input(input_lines: &str) -> Self::ParsedInput {
-        let lines = input_lines
-            .lines()
-            .map(|l| {
-                let mut items = l.split_whitespace();
-                let hand = items.next().unwrap().to_string();
-                Line::new(
-                    hand.clone(),
-                    get_hand_type(hand.clone()),
-                    items.next().unwrap().parse().unwrap(),
-                )
-            })
-            .collect::<Vec<Line>>();
+        Game::new(input_lines.lines().map(Line::parse).collect::<Vec<Line>>())
+    }
 
-        Game::new(lines)
+    fn parse_reader(reader: &mut dyn BufRead) -> io::Result<Self::ParsedInput> {
+        Ok(Game::new(parse_lines(reader, Line::parse)?))
     }
 
-    fn part_1(parsed_input: &Self::ParsedInput) -> String {
-        let mut game = parsed_input.clone();
-        game.get_total_winnings(false)
+    fn part_1(parsed_input: &mut Self::ParsedInput) -> String {
+        parsed_input.get_total_winnings(false)
     }
 
-    fn part_2(parsed_input: Self::ParsedInput) -> String {
-        let mut input = parsed_input;
-        for line in input.lines.iter_mut() {
-            if line.hand.contains('J') {
-                line.hand_type = line.get_hand_type_with_jokers();
-            }
-        }
+    fn part_2(parsed_input: &mut Self::ParsedInput) -> String {
+        parsed_input.use_jokers();
+        parsed_input.get_total_winnings(true)
+    }
 
-        input.get_total_winnings(true)
+    fn explain(parsed_input: &Self::ParsedInput, limit: usize) -> Vec<Explanation> {
+        let ranks = parsed_input.clone().get_ranks(false);
+        let mut with_jokers = parsed_input.clone();
+        with_jokers.use_jokers();
+        let joker_ranks = with_jokers.clone().get_ranks(true);
+
+        parsed_input
+            .lines
+            .iter()
+            .enumerate()
+            .take(limit)
+            .map(|(i, line)| Explanation {
+                item: format!("Hand {} (bid {})", line.hand, line.bid),
+                details: vec![
+                    format!(
+                        "{:?}, rank {} -> {}",
+                        line.hand_type,
+                        ranks[i],
+                        ranks[i] * line.bid
+                    ),
+                    format!(
+                        "with jokers: {:?}, rank {} -> {}",
+                        with_jokers.lines[i].hand_type,
+                        joker_ranks[i],
+                        joker_ranks[i] * line.bid
+                    ),
+                ],
+            })
+            .collect()
     }
 }
@@ -2,11 +2,12 @@ use std::collections::HashMap;
 
 use regex::Regex;
 
-use crate::Solution;
+use crate::{Assumption, Metadata, Solution};
 
 pub struct Day8;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Game {
     instructions: Vec<usize>,
     nodes: HashMap<String, Vec<String>>,
@@ -35,6 +36,49 @@ impl Game {
         steps
     }
 
+    // Part 2 takes the least common multiple of the first Z hits, which only
+    // works when every ghost is back on its Z node after as many steps again,
+    // with no other Z on the way. The walk is cut short when it runs longer
+    // than there are (node, instruction) states.
+    fn cycle_violation(&self, start: &str) -> Option<String> {
+        let limit = self.nodes.len() * self.instructions.len();
+        let mut hits: Vec<(usize, &str)> = vec![];
+        let mut current_node = start;
+        let mut steps = 0;
+
+        while hits.len() < 2 {
+            if steps > limit * 2 {
+                return Some(match hits.first() {
+                    Some((_, node)) => {
+                        format!("{} never reaches a Z node again after {}", start, node)
+                    }
+                    None => format!("{} never reaches a Z node", start),
+                });
+            }
+            let Some(next) = self.nodes.get(current_node) else {
+                return Some(format!(
+                    "{} leads to {}, which has no node",
+                    start, current_node
+                ));
+            };
+            current_node = &next[self.instructions[steps % self.instructions.len()]];
+            steps += 1;
+            if current_node.ends_with('Z') {
+                hits.push((steps, current_node));
+            }
+        }
+
+        let ((first, first_node), (second, second_node)) = (hits[0], hits[1]);
+        if first_node != second_node || second != first * 2 {
+            return Some(format!(
+                "{} reaches {} after {} steps, then {} after {}",
+                start, first_node, first, second_node, second
+            ));
+        }
+
+        None
+    }
+
     fn is_final_node(&self, node_name: &str, ends_z: bool) -> bool {
         if ends_z {
             return node_name.ends_with("Z");
@@ -63,6 +107,12 @@ fn get_lcm(numbers: &[usize]) -> usize {
 impl Solution for Day8 {
     type ParsedInput = Game;
 
+    const METADATA: Metadata = Metadata {
+        title: "Haunted Wasteland",
+        tags: &["cycle-detection", "math"],
+        algorithm: "walk each ghost to its first Z node and take the least common multiple",
+    };
+
     fn parse_input(input_lines: &str) -> Self::ParsedInput {
         let instructions = input_lines
             .lines()
@@ -89,7 +139,7 @@ impl Solution for Day8 {
         parsed_input.get_steps("AAA", false).to_string()
     }
 
-    fn part_2(parsed_input: Self::ParsedInput) -> String {
+    fn part_2(parsed_input: &Self::ParsedInput) -> String {
         let start_points = parsed_input.get_start_points();
         let all_steps: Vec<usize> = start_points
             .iter()
@@ -98,4 +148,16 @@ impl Solution for Day8 {
 
         get_lcm(&all_steps).to_string()
     }
+
+    fn assumptions(parsed_input: &Self::ParsedInput) -> Vec<Assumption> {
+        let mut start_points = parsed_input.get_start_points();
+        start_points.sort();
+
+        vec![Assumption {
+            statement: "each A node reaches a Z node in a cycle as long as the way there",
+            violation: start_points
+                .iter()
+                .find_map(|p| parsed_input.cycle_violation(p)),
+        }]
+    }
 }
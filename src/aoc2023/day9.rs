@@ -1,9 +1,20 @@
-use crate::Solution;
+use std::io::{self, BufRead};
+
+use crate::{parse_lines, Metadata, Solution};
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct History(Vec<i32>);
 
 impl History {
+    fn parse(line: &str) -> Self {
+        History(
+            line.split_whitespace()
+                .map(|n| n.parse::<i32>().unwrap())
+                .collect::<Vec<_>>(),
+        )
+    }
+
     fn extrapolate(&self, backwards: bool) -> Option<i32> {
         let values = self.get_next_values(&self.0);
 
@@ -51,19 +62,23 @@ pub struct Day9;
 impl Solution for Day9 {
     type ParsedInput = Vec<History>;
 
+    const METADATA: Metadata = Metadata {
+        title: "Mirage Maintenance",
+        tags: &["math"],
+        algorithm: "extrapolate from the differences, recursively",
+    };
+
     fn parse_input(input_lines: &str) -> Self::ParsedInput {
         input_lines
             .lines()
-            .map(|l| {
-                History(
-                    l.split_whitespace()
-                        .map(|n| n.parse::<i32>().unwrap())
-                        .collect::<Vec<_>>(),
-                )
-            })
+            .map(History::parse)
             .collect::<Vec<History>>()
     }
 
+    fn parse_reader(reader: &mut dyn BufRead) -> io::Result<Self::ParsedInput> {
+        parse_lines(reader, History::parse)
+    }
+
     fn part_1(parsed_input: &Self::ParsedInput) -> String {
         parsed_input
             .iter()
@@ -77,7 +92,7 @@ impl Solution for Day9 {
             .to_string()
     }
 
-    fn part_2(parsed_input: Self::ParsedInput) -> String {
+    fn part_2(parsed_input: &Self::ParsedInput) -> String {
         parsed_input
             .iter()
             .fold(0, |cur, h| {
@@ -0,0 +1,58 @@
+use std::io;
+
+use crate::{catch_panic, read_input_or_file, registry, Assumption};
+
+/// Checks the assumptions a day makes about its input, from `file` when given
+/// or from the input number `input` of the day. Returns whether they all hold.
+pub fn check_input(year: u16, day: u8, input: u8, file: Option<&str>) -> io::Result<bool> {
+    let Some(entry) = registry::find(year, day) else {
+        println!("{} day {} is not implemented", year, day);
+        return Ok(false);
+    };
+    let text = read_input_or_file(year, day, input, file)?;
+
+    let assumptions = match catch_panic(|| (entry.assumptions)(&text)) {
+        Ok(assumptions) => assumptions,
+        Err(e) => {
+            println!("ERROR  the input could not be parsed: {}", e);
+            return Ok(false);
+        }
+    };
+    if assumptions.is_empty() {
+        println!("No assumptions declared for {} day {}", year, day);
+        return Ok(true);
+    }
+
+    let mut held = 0;
+    for Assumption {
+        statement,
+        violation,
+    } in assumptions.iter()
+    {
+        match violation {
+            None => {
+                held += 1;
+                println!("ok    {}", statement);
+            }
+            Some(violation) => println!("FAIL  {}: {}", statement, violation),
+        }
+    }
+
+    println!();
+    println!("{}/{} assumptions hold", held, assumptions.len());
+
+    Ok(held == assumptions.len())
+}
+
+// Assumptions of `entry` that `input` breaks, as lines to print under a
+// failed run. Empty when they hold or the input cannot be parsed.
+pub(crate) fn broken(entry: &registry::Entry, input: &str) -> Vec<String> {
+    catch_panic(|| (entry.assumptions)(input))
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|a| {
+            a.violation
+                .map(|v| format!("assumption broken, {}: {}", a.statement, v))
+        })
+        .collect()
+}
@@ -0,0 +1,297 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{catch_panic, civil_date, config, format_time, read_input, registry, Part, Run};
+
+// Every benchmark run appends one line per day to the history file:
+// date, commit, machine, year, day, input, then the parse, part 1 and part 2
+// times in microseconds, all tab separated.
+
+const HISTORY_FILE: &str = "bench_history.tsv";
+// Earlier runs a new one is compared against, and shown in the trend.
+const BASELINE_RUNS: usize = 5;
+const TREND_RUNS: usize = 8;
+const TREND_BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+struct Record {
+    date: String,
+    commit: String,
+    machine: String,
+    year: u16,
+    day: u8,
+    input: u8,
+    // Parse, part 1 and part 2
+    times: [u64; 3],
+}
+
+impl Record {
+    fn parse(line: &str) -> Option<Self> {
+        let fields: Vec<&str> = line.split('\t').collect();
+        if fields.len() != 9 {
+            return None;
+        }
+
+        Some(Self {
+            date: fields[0].to_string(),
+            commit: fields[1].to_string(),
+            machine: fields[2].to_string(),
+            year: fields[3].parse().ok()?,
+            day: fields[4].parse().ok()?,
+            input: fields[5].parse().ok()?,
+            times: [
+                fields[6].parse().ok()?,
+                fields[7].parse().ok()?,
+                fields[8].parse().ok()?,
+            ],
+        })
+    }
+
+    fn line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+            self.date,
+            self.commit,
+            self.machine,
+            self.year,
+            self.day,
+            self.input,
+            self.times[0],
+            self.times[1],
+            self.times[2]
+        )
+    }
+
+    fn same_day(&self, other: &Record) -> bool {
+        self.machine == other.machine
+            && self.year == other.year
+            && self.day == other.day
+            && self.input == other.input
+    }
+}
+
+fn history_path() -> String {
+    format!("{}/{}", config::get().cache_dir(), HISTORY_FILE)
+}
+
+fn read_history() -> Vec<Record> {
+    fs::read_to_string(history_path())
+        .unwrap_or_default()
+        .lines()
+        .filter_map(Record::parse)
+        .collect()
+}
+
+fn append_history(records: &[Record]) -> io::Result<()> {
+    let path = history_path();
+    if let Some(dir) = Path::new(&path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    for record in records.iter() {
+        file.write_all(record.line().as_bytes())?;
+    }
+
+    Ok(())
+}
+
+fn commit() -> String {
+    Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+        .unwrap_or_else(|| String::from("unknown"))
+}
+
+fn machine() -> String {
+    fs::read_to_string("/etc/hostname")
+        .ok()
+        .or_else(|| std::env::var("HOSTNAME").ok())
+        .or_else(|| std::env::var("COMPUTERNAME").ok())
+        .map(|h| h.trim().to_string())
+        .filter(|h| !h.is_empty())
+        .unwrap_or_else(|| String::from("unknown"))
+}
+
+// UTC date and time of now, as 2023-12-01T05:00:00Z.
+fn now() -> String {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64;
+
+    let (year, month, day) = civil_date(seconds);
+    let time = seconds.rem_euclid(86400);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+fn median(mut times: Vec<u64>) -> u64 {
+    times.sort();
+    times.get(times.len() / 2).copied().unwrap_or(0)
+}
+
+// Median times of `runs` runs of a day.
+// Each part gets a run of its own, so neither is slowed down by the other
+// running at the same time.
+fn measure(run: fn(&str, Part) -> Run, input: &str, runs: usize) -> [u64; 3] {
+    let part_1: Vec<Run> = (0..runs).map(|_| run(input, Part::One)).collect();
+    let part_2: Vec<Run> = (0..runs).map(|_| run(input, Part::Two)).collect();
+    let micros = |time: Option<Duration>| time.unwrap_or_default().as_micros() as u64;
+
+    [
+        median(part_1.iter().map(|r| micros(Some(r.parse_time))).collect()),
+        median(
+            part_1
+                .iter()
+                .map(|r| micros(r.part_1.as_ref().map(|a| a.time)))
+                .collect(),
+        ),
+        median(
+            part_2
+                .iter()
+                .map(|r| micros(r.part_2.as_ref().map(|a| a.time)))
+                .collect(),
+        ),
+    ]
+}
+
+fn trend(totals: &[u64]) -> String {
+    let min = totals.iter().min().copied().unwrap_or(0);
+    let max = totals.iter().max().copied().unwrap_or(0);
+
+    totals
+        .iter()
+        .map(|t| {
+            if max == min {
+                TREND_BARS[0]
+            } else {
+                TREND_BARS[((t - min) * (TREND_BARS.len() as u64 - 1) / (max - min)) as usize]
+            }
+        })
+        .collect()
+}
+
+/// Benchmarks every built-in day of `year` (or just `day`) that has `input`,
+/// recording the median of `runs` runs in the history. With `compare`, parts
+/// slower than their baseline by more than the configured threshold are
+/// flagged. Returns whether none was.
+pub fn bench(
+    year: Option<u16>,
+    day: Option<u8>,
+    input: u8,
+    runs: usize,
+    compare: bool,
+) -> io::Result<bool> {
+    let history = read_history();
+    let threshold = config::get().bench_threshold;
+    let (date, commit, machine) = (now(), commit(), machine());
+
+    let mut records = vec![];
+    let mut regressions = 0;
+    for entry in registry::DAYS.iter() {
+        let first = registry::find(entry.year, entry.day).map(|e| e.name);
+        if year.is_some_and(|y| y != entry.year)
+            || day.is_some_and(|d| d != entry.day)
+            || first != Some(entry.name)
+        {
+            continue;
+        }
+        let Ok(input_str) = read_input(entry.year, entry.day, input) else {
+            continue;
+        };
+
+        let times = match catch_panic(|| measure(entry.run, &input_str, runs)) {
+            Ok(times) => times,
+            Err(e) => {
+                println!("{} day {:2}  failed: {}", entry.year, entry.day, e);
+                continue;
+            }
+        };
+        let record = Record {
+            date: date.clone(),
+            commit: commit.clone(),
+            machine: machine.clone(),
+            year: entry.year,
+            day: entry.day,
+            input,
+            times,
+        };
+
+        let earlier: Vec<&Record> = history.iter().filter(|r| r.same_day(&record)).collect();
+        let mut totals: Vec<u64> = earlier
+            .iter()
+            .rev()
+            .take(TREND_RUNS - 1)
+            .rev()
+            .map(|r| r.times.iter().sum())
+            .collect();
+        totals.push(record.times.iter().sum());
+
+        let time = |us: u64| format_time(Duration::from_micros(us));
+        println!(
+            "{} day {:2}  parse {}  part 1 {}  part 2 {}  {}",
+            record.year,
+            record.day,
+            time(record.times[0]),
+            time(record.times[1]),
+            time(record.times[2]),
+            trend(&totals)
+        );
+
+        if compare {
+            let baseline: Vec<&&Record> = earlier.iter().rev().take(BASELINE_RUNS).collect();
+            for (i, name) in ["parse", "part 1", "part 2"].iter().enumerate() {
+                if baseline.is_empty() {
+                    break;
+                }
+                let base = median(baseline.iter().map(|r| r.times[i]).collect());
+                let now = record.times[i];
+                if base > 0 && now as f64 > base as f64 * (1.0 + threshold / 100.0) {
+                    regressions += 1;
+                    println!(
+                        "    SLOWER {}: {} -> {} (+{:.0}% over the last {} runs)",
+                        name,
+                        time(base),
+                        time(now),
+                        (now as f64 / base as f64 - 1.0) * 100.0,
+                        baseline.len()
+                    );
+                }
+            }
+        }
+
+        records.push(record);
+    }
+
+    if records.is_empty() {
+        println!("Nothing to benchmark: no day has input {}", input);
+    }
+    append_history(&records)?;
+
+    if compare && regressions > 0 {
+        println!(
+            "{} regression(s) over the {}% threshold",
+            regressions, threshold
+        );
+    }
+
+    Ok(regressions == 0)
+}
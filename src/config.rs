@@ -0,0 +1,311 @@
+use std::{
+    env, fs,
+    io::{self, IsTerminal},
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+// Settings are read from `aoc.toml` at the project root, then from the user's
+// `~/.config/aoc23/aoc.toml`, each file overriding the keys it sets. Command
+// line flags of the same names (`--data-dir` for `data_dir`) override both.
+
+pub const PROJECT_FILE: &str = "aoc.toml";
+pub const DATA_DIR_VAR: &str = "AOC_DATA_DIR";
+
+/// Keys accepted in the files, most of them also as flags.
+pub const KEYS: [&str; 11] = [
+    "year",
+    "data_dir",
+    "input",
+    "format",
+    "time_unit",
+    "colour",
+    "jobs",
+    "cache_dir",
+    "bench_threshold",
+    "stream_threshold",
+    "on_disk",
+];
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    Seconds,
+    Millis,
+    Micros,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colour {
+    Auto,
+    Always,
+    Never,
+}
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Year used when the command line only gives a day.
+    pub year: Option<u16>,
+    /// Data directory, searched for when not set. See `data_dir()`.
+    pub data_dir: Option<String>,
+    /// Input used when the command line does not give one.
+    pub input: u8,
+    pub format: Format,
+    pub time_unit: TimeUnit,
+    pub colour: Colour,
+    /// Threads used to run several solutions at once.
+    pub jobs: usize,
+    /// Where results and downloads are kept, the data directory by default.
+    pub cache_dir: Option<String>,
+    /// Percentage a benchmark may get slower than its baseline.
+    pub bench_threshold: f64,
+    /// Size in MB above which inputs are parsed as they are read.
+    pub stream_threshold: u64,
+    /// Read the files of the data directory even when the binary was built
+    /// with copies of them (the `embed-inputs` feature).
+    pub on_disk: bool,
+    resolved_data_dir: OnceLock<Result<PathBuf, String>>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            year: None,
+            data_dir: None,
+            input: 0,
+            format: Format::Text,
+            time_unit: TimeUnit::Millis,
+            colour: Colour::Auto,
+            jobs: std::thread::available_parallelism().map_or(1, |n| n.get()),
+            cache_dir: None,
+            bench_threshold: 10.0,
+            stream_threshold: 64,
+            on_disk: false,
+            resolved_data_dir: OnceLock::new(),
+        }
+    }
+}
+
+/// Per-user settings directory, `~/.config/aoc23`.
+pub fn user_dir() -> Option<PathBuf> {
+    let dir = match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(env::var_os("HOME")?).join(".config"),
+    };
+
+    Some(dir.join("aoc23"))
+}
+
+fn relative_to(base: &Path, dir: &str) -> String {
+    base.join(dir).display().to_string()
+}
+
+fn user_file() -> Option<PathBuf> {
+    user_dir().map(|dir| dir.join("aoc.toml"))
+}
+
+impl Config {
+    /// Defaults overridden by the project file, then by the user file.
+    pub fn load() -> io::Result<Self> {
+        let mut config = Self::default();
+        let project = project_root().unwrap_or_default().join(PROJECT_FILE);
+        let files = [Some(project), user_file()];
+
+        for path in files.iter().flatten() {
+            let text = match fs::read_to_string(path) {
+                Ok(text) => text,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e),
+            };
+
+            let before = (config.data_dir.clone(), config.cache_dir.clone());
+            config.merge(&text).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {}", path.display(), e),
+                )
+            })?;
+
+            // Directories set in a file are relative to that file, only the
+            // flags are relative to the current directory.
+            let base = path.parent().unwrap_or(Path::new(""));
+            if config.data_dir != before.0 {
+                config.data_dir = config.data_dir.map(|dir| relative_to(base, &dir));
+            }
+            if config.cache_dir != before.1 {
+                config.cache_dir = config.cache_dir.map(|dir| relative_to(base, &dir));
+            }
+        }
+
+        Ok(config)
+    }
+
+    fn merge(&mut self, text: &str) -> Result<(), String> {
+        let table: toml::Table = text
+            .parse()
+            .map_err(|e: toml::de::Error| e.message().to_string())?;
+
+        for (key, value) in table.iter() {
+            let value = match value {
+                toml::Value::String(s) => s.clone(),
+                toml::Value::Integer(i) => i.to_string(),
+                toml::Value::Float(f) => f.to_string(),
+                toml::Value::Boolean(b) => b.to_string(),
+                _ => return Err(format!("unsupported value for {}", key)),
+            };
+            self.set(key, &value)?;
+        }
+
+        Ok(())
+    }
+
+    /// Sets one of `KEYS` from its textual value.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let invalid = || format!("invalid {} {:?}", key, value);
+
+        match key {
+            "year" => self.year = Some(value.parse().map_err(|_| invalid())?),
+            "data_dir" => self.data_dir = Some(value.to_string()),
+            "input" => self.input = value.parse().ok().filter(|i| *i <= 2).ok_or_else(invalid)?,
+            "format" => {
+                self.format = match value {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    _ => return Err(invalid()),
+                }
+            }
+            "time_unit" => {
+                self.time_unit = match value {
+                    "s" => TimeUnit::Seconds,
+                    "ms" => TimeUnit::Millis,
+                    "us" => TimeUnit::Micros,
+                    _ => return Err(invalid()),
+                }
+            }
+            "colour" => {
+                self.colour = match value {
+                    "auto" => Colour::Auto,
+                    "always" | "true" => Colour::Always,
+                    "never" | "false" => Colour::Never,
+                    _ => return Err(invalid()),
+                }
+            }
+            "jobs" => self.jobs = value.parse().ok().filter(|j| *j > 0).ok_or_else(invalid)?,
+            "cache_dir" => self.cache_dir = Some(value.to_string()),
+            "bench_threshold" => {
+                self.bench_threshold = value
+                    .parse()
+                    .ok()
+                    .filter(|t| *t >= 0.0)
+                    .ok_or_else(invalid)?
+            }
+            "stream_threshold" => self.stream_threshold = value.parse().map_err(|_| invalid())?,
+            "on_disk" => self.on_disk = value.parse().map_err(|_| invalid())?,
+            _ => return Err(format!("unknown key {}", key)),
+        }
+
+        Ok(())
+    }
+
+    /// The first existing directory out of `AOC_DATA_DIR`, the configured
+    /// `data_dir` and `data` at the project root. When there is none, the
+    /// error lists every location that was tried. An `AOC_DATA_DIR` that is
+    /// not a directory is an error of its own.
+    pub fn data_dir(&self) -> io::Result<&Path> {
+        self.resolved_data_dir
+            .get_or_init(|| self.find_data_dir())
+            .as_deref()
+            .map_err(|tried| io::Error::new(io::ErrorKind::NotFound, tried.clone()))
+    }
+
+    fn find_data_dir(&self) -> Result<PathBuf, String> {
+        // Directories to try in order, or why a location could not be tried.
+        let mut candidates: Vec<Result<PathBuf, String>> = vec![];
+
+        match env::var_os(DATA_DIR_VAR) {
+            // Set on purpose, so it is not silently passed over.
+            Some(dir) if !Path::new(&dir).is_dir() => {
+                return Err(format!(
+                    "{} is set to {}, which is not a directory",
+                    DATA_DIR_VAR,
+                    Path::new(&dir).display()
+                ))
+            }
+            Some(dir) => candidates.push(Ok(PathBuf::from(dir))),
+            None => candidates.push(Err(format!("{} (not set)", DATA_DIR_VAR))),
+        }
+
+        if let Some(dir) = &self.data_dir {
+            candidates.push(Ok(PathBuf::from(dir)));
+        }
+
+        match &project_root() {
+            Some(root) => candidates.push(Ok(root.join("data"))),
+            None => candidates.push(Err(format!(
+                "project root (no {} or Cargo.toml above the current directory)",
+                PROJECT_FILE
+            ))),
+        }
+
+        let mut tried = vec![];
+        for candidate in candidates {
+            match candidate {
+                Ok(dir) if dir.is_dir() => return Ok(dir),
+                Ok(dir) => tried.push(dir.display().to_string()),
+                Err(note) => tried.push(note),
+            }
+        }
+
+        Err(format!(
+            "No data directory found, tried:\n    {}",
+            tried.join("\n    ")
+        ))
+    }
+
+    /// `cache_dir`, or else the data directory, or `data` when there is none
+    /// so results can still be recorded.
+    pub fn cache_dir(&self) -> String {
+        match &self.cache_dir {
+            Some(dir) => dir.clone(),
+            None => self
+                .data_dir()
+                .map(|d| d.display().to_string())
+                .unwrap_or_else(|_| String::from("data")),
+        }
+    }
+
+    pub fn use_colour(&self) -> bool {
+        match self.colour {
+            Colour::Auto => io::stdout().is_terminal(),
+            Colour::Always => true,
+            Colour::Never => false,
+        }
+    }
+}
+
+/// Closest directory, from the current one upwards, holding an `aoc.toml` or a
+/// `Cargo.toml`.
+pub fn project_root() -> Option<PathBuf> {
+    let cwd = env::current_dir().ok()?;
+    cwd.ancestors()
+        .find(|dir| dir.join(PROJECT_FILE).is_file() || dir.join("Cargo.toml").is_file())
+        .map(Path::to_path_buf)
+}
+
+/// Makes `config` the one used by the rest of the crate. Only the first call
+/// has an effect.
+pub fn init(config: Config) {
+    let _ = CONFIG.set(config);
+}
+
+/// The configuration given to `init`, or the defaults when there was none.
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
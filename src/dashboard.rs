@@ -0,0 +1,248 @@
+use std::{io, path::Path};
+
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    DefaultTerminal, Frame,
+};
+
+use crate::{
+    available_days, data_path, day_title, format_time, input_file_name,
+    plugin::{Plugins, PLUGINS_DIR},
+    read_input, read_problem, results, try_run_day, Answer, Part,
+};
+
+const INPUTS: [u8; 3] = [0, 1, 2];
+const INPUT_NAMES: [&str; 3] = ["input", "example1", "example2"];
+const INPUT_MARKS: [char; 3] = ['i', '1', '2'];
+
+const HELP: &str =
+    "j/k: select  i: change input  r: run  1/2: run part  p: reload plugins  PgUp/PgDn: scroll  q: quit";
+
+struct Dashboard {
+    plugins: Plugins,
+    days: Vec<(u16, u8)>,
+    selected: ListState,
+    input: u8,
+    problem: String,
+    scroll: u16,
+    status: String,
+}
+
+impl Dashboard {
+    fn new() -> Self {
+        let mut dashboard = Self {
+            plugins: Plugins::new(PLUGINS_DIR),
+            days: vec![],
+            selected: ListState::default().with_selected(Some(0)),
+            input: 0,
+            problem: String::new(),
+            scroll: 0,
+            status: String::from(HELP),
+        };
+        dashboard.reload_plugins();
+        dashboard.load_problem();
+
+        dashboard
+    }
+
+    fn reload_plugins(&mut self) {
+        if let Some(e) = self.plugins.reload().pop() {
+            self.status = format!("Error: {}", e);
+        }
+
+        self.days = available_days(&self.plugins);
+    }
+
+    fn selected(&self) -> (u16, u8) {
+        self.days[self
+            .selected
+            .selected()
+            .unwrap_or(0)
+            .min(self.days.len() - 1)]
+    }
+
+    fn select(&mut self, offset: isize) {
+        let current = self.selected.selected().unwrap_or(0) as isize;
+        let next = (current + offset).rem_euclid(self.days.len() as isize);
+        self.selected.select(Some(next as usize));
+        self.load_problem();
+    }
+
+    fn load_problem(&mut self) {
+        let (year, day) = self.selected();
+
+        self.scroll = 0;
+        self.problem =
+            read_problem(year, day).unwrap_or_else(|_| String::from("No problem statement found."));
+    }
+
+    fn run(&mut self, part: Part) {
+        // Picks up plugins rebuilt since the last run.
+        self.reload_plugins();
+
+        let (year, day) = self.selected();
+        let input = match read_input(year, day, self.input) {
+            Ok(input) => input,
+            Err(e) => {
+                self.status = format!("Error: {}", e);
+                return;
+            }
+        };
+
+        // A failing solution must not take the terminal down with it.
+        match try_run_day(&self.plugins, year, day, input.as_str(), part) {
+            Ok(Some(run)) => {
+                self.status = format!(
+                    "{} day {} ({}): parsing {}",
+                    year,
+                    day,
+                    INPUT_NAMES[self.input as usize],
+                    format_time(run.parse_time)
+                );
+                if let Err(e) = results::record(year, day, self.input, &run) {
+                    self.status = format!("Error: {}", e);
+                }
+            }
+            Ok(None) => self.status = format!("{} day {} is not implemented", year, day),
+            Err(e) => self.status = format!("{} day {} panicked: {}", year, day, e),
+        }
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main, status] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let [list, problem] =
+            Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .areas(main);
+
+        let items: Vec<ListItem> = self
+            .days
+            .iter()
+            .map(|(year, day)| self.day_item(*year, *day))
+            .collect();
+        let title = format!(" Days ({}) ", INPUT_NAMES[self.input as usize]);
+        frame.render_stateful_widget(
+            List::new(items)
+                .block(Block::default().borders(Borders::ALL).title(title))
+                .highlight_style(Style::default().add_modifier(Modifier::REVERSED)),
+            list,
+            &mut self.selected,
+        );
+
+        frame.render_widget(
+            Paragraph::new(self.problem.as_str())
+                .block(Block::default().borders(Borders::ALL).title(" Problem "))
+                .wrap(Wrap { trim: false })
+                .scroll((self.scroll, 0)),
+            problem,
+        );
+
+        frame.render_widget(
+            Paragraph::new(self.status.as_str()).style(Style::default().fg(Color::Yellow)),
+            status,
+        );
+    }
+
+    fn day_item(&self, year: u16, day: u8) -> ListItem<'static> {
+        let path = data_path(year, day).ok();
+        let inputs = INPUTS
+            .iter()
+            .map(|i| {
+                let file = path
+                    .as_ref()
+                    .map(|path| Path::new(path).join(input_file_name(*i)));
+                if file.is_some_and(|file| file.exists()) {
+                    INPUT_MARKS[*i as usize]
+                } else {
+                    '-'
+                }
+            })
+            .collect::<String>();
+
+        let last = match results::last(year, day, self.input) {
+            Some(run) => format!(
+                "{}  {}",
+                format_answer(&run.part_1),
+                format_answer(&run.part_2)
+            ),
+            None => String::from("not run"),
+        };
+
+        let source = if self.plugins.find(year, day).is_some() {
+            "plugin "
+        } else {
+            ""
+        };
+
+        ListItem::new(Line::from(format!(
+            "{} day {:2}  {:32} [{}]  {}{}",
+            year,
+            day,
+            day_title(year, day),
+            inputs,
+            source,
+            last
+        )))
+    }
+}
+
+fn format_answer(answer: &Option<Answer>) -> String {
+    match answer {
+        Some(a) => format!("{} ({})", a.value, format_time(a.time).trim()),
+        None => String::from("-"),
+    }
+}
+
+fn event_loop(terminal: &mut DefaultTerminal) -> io::Result<()> {
+    let mut dashboard = Dashboard::new();
+
+    loop {
+        terminal.draw(|frame| dashboard.draw(frame))?;
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Char('j') | KeyCode::Down => dashboard.select(1),
+            KeyCode::Char('k') | KeyCode::Up => dashboard.select(-1),
+            KeyCode::Char('i') | KeyCode::Tab => dashboard.input = (dashboard.input + 1) % 3,
+            KeyCode::Char('p') => {
+                dashboard.reload_plugins();
+                dashboard.load_problem();
+            }
+            KeyCode::PageDown => dashboard.scroll = dashboard.scroll.saturating_add(10),
+            KeyCode::PageUp => dashboard.scroll = dashboard.scroll.saturating_sub(10),
+            KeyCode::Char(c @ ('r' | '1' | '2')) => {
+                dashboard.status = String::from("Running...");
+                terminal.draw(|frame| dashboard.draw(frame))?;
+                dashboard.run(match c {
+                    '1' => Part::One,
+                    '2' => Part::Two,
+                    _ => Part::Both,
+                });
+                // Solutions may write debug output to the terminal.
+                terminal.clear()?;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Full-screen dashboard listing every registered day with its available
+/// inputs and last recorded answers.
+pub fn run() -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal);
+    ratatui::restore();
+
+    result
+}
@@ -0,0 +1,40 @@
+use std::{fs, io};
+
+use crate::{catch_panic, read_input_or_file, registry};
+
+/// Writes the parsed input of a day as JSON, to `output` when given or to
+/// stdout. The input is `file`, or the input number `input` of the day.
+/// Returns whether the input could be parsed and written out.
+pub fn dump_parsed(
+    year: u16,
+    day: u8,
+    input: u8,
+    file: Option<&str>,
+    output: Option<&str>,
+) -> io::Result<bool> {
+    let Some(entry) = registry::find(year, day) else {
+        println!("{} day {} is not implemented", year, day);
+        return Ok(false);
+    };
+    let text = read_input_or_file(year, day, input, file)?;
+
+    let parsed = match catch_panic(|| (entry.parsed_json)(&text)) {
+        Ok(Ok(parsed)) => parsed,
+        Ok(Err(e)) => {
+            eprintln!("Error: the parsed input has no JSON form: {}", e);
+            return Ok(false);
+        }
+        Err(e) => {
+            eprintln!("Error: the input could not be parsed: {}", e);
+            return Ok(false);
+        }
+    };
+    let json = serde_json::to_string_pretty(&parsed)?;
+
+    match output {
+        Some(output) => fs::write(output, json + "\n")?,
+        None => println!("{}", json),
+    }
+
+    Ok(true)
+}
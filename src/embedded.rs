@@ -0,0 +1,19 @@
+use crate::config;
+
+// `FILES`, the `data/aoc<year>/day<N>/*.txt` found at build time, keyed by
+// their path below `data`. See build.rs.
+include!(concat!(env!("OUT_DIR"), "/embedded.rs"));
+
+/// Copy of `file` of a day built into the binary, unless `on_disk` asks for
+/// the one in the data directory.
+pub fn get(year: u16, day: u8, file: &str) -> Option<&'static str> {
+    if config::get().on_disk {
+        return None;
+    }
+
+    let key = format!("aoc{}/day{}/{}", year, day, file);
+    FILES
+        .iter()
+        .find(|(path, _)| *path == key)
+        .map(|(_, text)| *text)
+}
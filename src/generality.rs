@@ -0,0 +1,145 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    assumptions, catch_panic, config, format_time, load_plugins, mismatches, not_implemented,
+    read_answers, registry, vault, Part,
+};
+
+// Other people's inputs live in `data/aoc<year>/inputs/<owner>/day<N>.txt`,
+// with their accepted answers, one line per part, in `day<N>.answers.txt`.
+
+enum Outcome {
+    Pass,
+    Fail(Vec<String>),
+    Unchecked(String),
+    Error(String),
+}
+
+fn inputs_dir(year: u16) -> io::Result<PathBuf> {
+    Ok(config::get()
+        .data_dir()?
+        .join(format!("aoc{}", year))
+        .join("inputs"))
+}
+
+fn owners(year: u16, day: u8) -> io::Result<Vec<(String, PathBuf)>> {
+    let dir = inputs_dir(year)?;
+    let mut owners = vec![];
+
+    for entry in fs::read_dir(&dir)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", dir.display(), e)))?
+    {
+        let path = entry?.path();
+        let input = path.join(format!("day{}.txt", day));
+        let encrypted = path.join(format!("day{}.txt.{}", day, vault::EXTENSION));
+        if input.is_file() || encrypted.is_file() {
+            let owner = path.file_name().unwrap_or_default().to_string_lossy();
+            owners.push((owner.to_string(), path));
+        }
+    }
+    owners.sort();
+
+    Ok(owners)
+}
+
+fn check_owner(entry: &registry::Entry, dir: &Path, day: u8) -> (Outcome, String) {
+    let input = match vault::read(&dir.join(format!("day{}.txt", day))) {
+        Ok(input) => input,
+        Err(e) => return (Outcome::Error(e.to_string()), String::new()),
+    };
+
+    let run = match catch_panic(|| (entry.run)(&input, Part::Both)) {
+        Ok(run) => run,
+        Err(e) => return (Outcome::Error(format!("panicked: {}", e)), String::new()),
+    };
+    let time = format_time(
+        run.parse_time
+            + run
+                .part_1
+                .iter()
+                .chain(run.part_2.iter())
+                .map(|a| a.time)
+                .sum(),
+    );
+    let got = [&run.part_1, &run.part_2].map(|a| a.as_ref().map(|a| a.value.clone()));
+
+    let expected = match read_answers(&dir.join(format!("day{}.answers.txt", day))) {
+        Ok(expected) => expected,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            let got = got.map(|g| g.unwrap_or_default());
+            return (
+                Outcome::Unchecked(format!("part 1: {}  part 2: {}", got[0], got[1])),
+                time,
+            );
+        }
+        Err(e) => return (Outcome::Error(e.to_string()), time),
+    };
+
+    let mut mismatches = mismatches(&expected, &got);
+
+    if mismatches.is_empty() {
+        (Outcome::Pass, time)
+    } else {
+        mismatches.extend(assumptions::broken(entry, &input));
+        (Outcome::Fail(mismatches), time)
+    }
+}
+
+/// Runs a day on every owner's input and compares the answers with the ones
+/// they recorded. Returns whether every input passed.
+pub fn check(year: u16, day: u8) -> io::Result<bool> {
+    let entry =
+        registry::find(year, day).ok_or_else(|| not_implemented(&load_plugins(), year, day))?;
+
+    // A check that ran nothing does not pass.
+    let owners = owners(year, day)?;
+    if owners.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "No inputs for day {} in {}",
+                day,
+                inputs_dir(year)?.display()
+            ),
+        ));
+    }
+
+    let width = owners.iter().map(|(o, _)| o.len()).max().unwrap_or(0);
+    let mut passed = 0;
+    let mut failed = 0;
+    for (owner, dir) in owners.iter() {
+        let (outcome, time) = check_owner(entry, dir, day);
+        match outcome {
+            Outcome::Pass => {
+                passed += 1;
+                println!("{:width$}  pass   {}", owner, time, width = width);
+            }
+            Outcome::Fail(mismatches) => {
+                failed += 1;
+                println!("{:width$}  FAIL   {}", owner, time, width = width);
+                mismatches.iter().for_each(|m| println!("    {}", m));
+            }
+            Outcome::Unchecked(answers) => {
+                println!(
+                    "{:width$}  ?      {}  no recorded answers, got {}",
+                    owner,
+                    time,
+                    answers,
+                    width = width
+                );
+            }
+            Outcome::Error(e) => {
+                failed += 1;
+                println!("{:width$}  ERROR  {}", owner, e, width = width);
+            }
+        }
+    }
+
+    println!();
+    println!("{}/{} inputs passed", passed, owners.len());
+
+    Ok(failed == 0)
+}
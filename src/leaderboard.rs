@@ -0,0 +1,241 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::Path,
+    time::{Duration, SystemTime},
+};
+
+use serde_json::Value;
+
+use crate::config;
+
+// AoC asks not to request a leaderboard more than once every 15 minutes.
+const FETCH_EVERY: Duration = Duration::from_secs(15 * 60);
+
+struct Member {
+    name: String,
+    // Star timestamps of both parts, by day
+    days: BTreeMap<u8, [Option<i64>; 2]>,
+}
+
+impl Member {
+    fn stars(&self) -> usize {
+        self.days.values().flatten().filter(|t| t.is_some()).count()
+    }
+}
+
+pub struct Leaderboard {
+    year: u16,
+    members: Vec<Member>,
+}
+
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146097 + doe - 719468
+}
+
+// Puzzles unlock at midnight EST, which is 05:00 UTC.
+fn unlock_time(year: u16, day: u8) -> i64 {
+    days_from_civil(year as i64, 12, day as i64) * 86400 + 5 * 3600
+}
+
+fn format_duration(seconds: i64) -> String {
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+fn timestamp(value: &Value) -> Option<i64> {
+    value
+        .as_i64()
+        .or_else(|| value.as_str().and_then(|s| s.parse().ok()))
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> io::Result<Self> {
+        let invalid = |what: &str| io::Error::new(io::ErrorKind::InvalidData, what.to_string());
+        let root: Value = serde_json::from_str(json).map_err(io::Error::other)?;
+
+        let year = root["event"]
+            .as_str()
+            .and_then(|e| e.parse().ok())
+            .ok_or_else(|| invalid("missing event year"))?;
+
+        let members = root["members"]
+            .as_object()
+            .ok_or_else(|| invalid("missing members"))?
+            .values()
+            .map(|m| {
+                let name = match m["name"].as_str() {
+                    Some(name) => name.to_string(),
+                    None => format!("(anonymous user #{})", m["id"]),
+                };
+
+                let mut days = BTreeMap::new();
+                if let Some(levels) = m["completion_day_level"].as_object() {
+                    for (day, parts) in levels.iter() {
+                        let Ok(day) = day.parse() else {
+                            continue;
+                        };
+                        days.insert(
+                            day,
+                            [
+                                timestamp(&parts["1"]["get_star_ts"]),
+                                timestamp(&parts["2"]["get_star_ts"]),
+                            ],
+                        );
+                    }
+                }
+
+                Member { name, days }
+            })
+            .collect();
+
+        Ok(Self { year, members })
+    }
+
+    // Local score after each day: on every star, the fastest member gets as
+    // many points as there are members, the next one one less, and so on.
+    fn points_timeline(&self) -> (Vec<u8>, Vec<Vec<usize>>) {
+        let days: Vec<u8> = self
+            .members
+            .iter()
+            .flat_map(|m| m.days.keys().copied())
+            .collect::<std::collections::BTreeSet<u8>>()
+            .into_iter()
+            .collect();
+
+        let mut total = vec![0; self.members.len()];
+        let mut timeline = vec![vec![]; self.members.len()];
+        for day in days.iter() {
+            for part in 0..2 {
+                let mut stars: Vec<(i64, usize)> = self
+                    .members
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, m)| m.days.get(day).and_then(|d| d[part]).map(|t| (t, i)))
+                    .collect();
+                stars.sort();
+
+                for (rank, (_, i)) in stars.iter().enumerate() {
+                    total[*i] += self.members.len() - rank;
+                }
+            }
+
+            for (i, points) in total.iter().enumerate() {
+                timeline[i].push(*points);
+            }
+        }
+
+        (days, timeline)
+    }
+
+    pub fn print(&self) {
+        let (days, timeline) = self.points_timeline();
+
+        let mut order: Vec<usize> = (0..self.members.len()).collect();
+        order.sort_by_key(|i| std::cmp::Reverse(timeline[*i].last().copied().unwrap_or(0)));
+
+        for i in order.iter() {
+            let member = &self.members[*i];
+            println!(
+                "{} ({} stars, {} points)",
+                member.name,
+                member.stars(),
+                timeline[*i].last().copied().unwrap_or(0)
+            );
+
+            for (day, [part_1, part_2]) in member.days.iter() {
+                let unlock = unlock_time(self.year, *day);
+                let time = |t: &Option<i64>| match t {
+                    Some(t) => format_duration(t - unlock),
+                    None => String::from("-"),
+                };
+                let delta = match (part_1, part_2) {
+                    (Some(p1), Some(p2)) => format!("  (+{})", format_duration(p2 - p1)),
+                    _ => String::new(),
+                };
+
+                println!(
+                    "    day {:2}  part 1 {:>10}  part 2 {:>10}{}",
+                    day,
+                    time(part_1),
+                    time(part_2),
+                    delta
+                );
+            }
+            println!();
+        }
+
+        let width = self.members.iter().map(|m| m.name.len()).max().unwrap_or(0);
+        print!("{:width$}", "Points", width = width);
+        days.iter()
+            .for_each(|d| print!(" {:>5}", format!("d{}", d)));
+        println!();
+        for i in order.iter() {
+            print!("{:width$}", self.members[*i].name, width = width);
+            timeline[*i].iter().for_each(|p| print!(" {:>5}", p));
+            println!();
+        }
+    }
+}
+
+fn cache_path(year: u16, id: &str) -> String {
+    format!(
+        "{}/aoc{}/leaderboard_{}.json",
+        config::get().cache_dir(),
+        year,
+        id
+    )
+}
+
+/// Downloads a private leaderboard with the `AOC_SESSION` cookie, reusing the
+/// saved copy when it is recent enough.
+pub fn fetch(year: u16, id: &str) -> io::Result<String> {
+    let path = cache_path(year, id);
+    let recent = fs::metadata(&path)
+        .and_then(|m| m.modified())
+        .map(|m| SystemTime::now().duration_since(m).unwrap_or_default() < FETCH_EVERY)
+        .unwrap_or(false);
+    if recent {
+        return fs::read_to_string(path);
+    }
+
+    let session = std::env::var("AOC_SESSION")
+        .ok()
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                "Please set the AOC_SESSION environment variable",
+            )
+        })?;
+
+    let json = ureq::get(&format!(
+        "https://adventofcode.com/{}/leaderboard/private/view/{}.json",
+        year, id
+    ))
+    .set("Cookie", &format!("session={}", session))
+    .call()
+    .map_err(io::Error::other)?
+    .into_string()?;
+
+    if let Some(dir) = Path::new(&path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, &json)?;
+
+    Ok(json)
+}
+
+pub fn load(path: &str) -> io::Result<Leaderboard> {
+    Leaderboard::parse(&fs::read_to_string(path)?)
+}
@@ -1,110 +1,926 @@
 #![feature(lazy_cell)]
 #![feature(extract_if)]
 
+pub mod animation;
 pub mod aoc2023;
+pub mod assumptions;
+pub mod bench;
+pub mod config;
+pub mod dashboard;
+#[cfg(feature = "serde")]
+pub mod dump;
+#[cfg(feature = "embed-inputs")]
+pub mod embedded;
+pub mod generality;
+pub mod leaderboard;
+pub mod plugin;
+pub mod progress;
+pub mod registry;
+pub mod render;
+pub mod report;
+pub mod results;
+pub mod server;
+pub mod show;
+pub mod vault;
+pub mod verify;
 
+use std::any::Any;
+use std::cell::Cell;
+use std::fmt;
 use std::fs::File;
-use std::io::Read;
-
-// use aoc2023::day1;
-// use aoc2023::day2;
-use aoc2023::day10;
-use aoc2023::day11;
-use aoc2023::day12;
-use aoc2023::day13;
-use aoc2023::day14;
-use aoc2023::day15;
-use aoc2023::day16;
-use aoc2023::day17;
-use aoc2023::day3;
-use aoc2023::day4;
-use aoc2023::day5;
-use aoc2023::day6;
-use aoc2023::day7;
-use aoc2023::day8;
-use aoc2023::day9;
+use std::io::{BufRead, BufReader, IsTerminal};
+use std::panic;
+use std::path::Path;
+use std::sync::Once;
+use std::time::{Duration, Instant};
+
+use animation::Frames;
+use plugin::Plugins;
+use serde_json::{json, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+    Both,
+}
+
+impl Part {
+    fn includes(&self, part: Part) -> bool {
+        *self == Part::Both || *self == part
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Answer {
+    pub value: String,
+    pub time: Duration,
+}
+
+/// One item of a walkthrough (a card, a seed, a hand...) and what the
+/// solution did with it.
+#[derive(Debug, Clone)]
+pub struct Explanation {
+    pub item: String,
+    pub details: Vec<String>,
+}
+
+impl Explanation {
+    pub fn print(&self) {
+        println!("{}", self.item);
+        for detail in self.details.iter() {
+            println!("    {}", detail);
+        }
+    }
+}
+
+/// A property of the real inputs that a solution relies on without checking
+/// it, like a square grid.
+#[derive(Debug, Clone)]
+pub struct Assumption {
+    pub statement: &'static str,
+    /// How the input breaks the assumption, or `None` when it holds.
+    pub violation: Option<String>,
+}
+
+/// Topics a day can be tagged with, for `list --tag`.
+pub const TAGS: [&str; 10] = [
+    "cycle-detection",
+    "dynamic-programming",
+    "grid",
+    "hashing",
+    "intervals",
+    "math",
+    "parsing",
+    "shortest-path",
+    "simulation",
+    "sorting",
+];
+
+/// What a day is about: the title of the puzzle, its topics out of `TAGS`,
+/// and how the implementation solves it.
+#[derive(Debug, Clone, Copy)]
+pub struct Metadata {
+    pub title: &'static str,
+    pub tags: &'static [&'static str],
+    pub algorithm: &'static str,
+}
+
+impl Metadata {
+    pub const NONE: Metadata = Metadata {
+        title: "",
+        tags: &[],
+        algorithm: "",
+    };
+}
+
+/// Answers and timings of a single run. A part that was not run is `None`.
+#[derive(Debug, Clone, Default)]
+pub struct Run {
+    pub parse_time: Duration,
+    pub part_1: Option<Answer>,
+    pub part_2: Option<Answer>,
+}
+
+fn answer_json(answer: &Option<Answer>) -> Value {
+    match answer {
+        Some(a) => json!({ "answer": a.value, "time_us": a.time.as_micros() as u64 }),
+        None => Value::Null,
+    }
+}
+
+impl Run {
+    /// Prints the run in the configured output format.
+    pub fn print(&self) {
+        let config = config::get();
+        if config.format == config::Format::Json {
+            println!("{}", self.to_json());
+            return;
+        }
+
+        let colour = config.use_colour();
+        let answers = [("Part 1", &self.part_1), ("Part 2", &self.part_2)];
+
+        println!("Parsing: {}", format_time(self.parse_time));
+        for (name, answer) in answers.iter() {
+            if let Some(answer) = answer {
+                if colour {
+                    println!(
+                        "{}: {} -> \x1b[1;32m{}\x1b[0m",
+                        name,
+                        format_time(answer.time),
+                        answer.value
+                    );
+                } else {
+                    println!("{}: {} -> {}", name, format_time(answer.time), answer.value);
+                }
+            }
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "parse_time_us": self.parse_time.as_micros() as u64,
+            "part_1": answer_json(&self.part_1),
+            "part_2": answer_json(&self.part_2),
+        })
+    }
+}
+
+/// Year, month and day of `seconds` since the epoch, in UTC.
+pub fn civil_date(seconds: i64) -> (i64, i64, i64) {
+    // Days since the epoch to a civil date, from Howard Hinnant's algorithms.
+    let z = seconds.div_euclid(86400) + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
+
+/// The year it is now in UTC, the last one that can have puzzles.
+pub fn current_year() -> u16 {
+    let seconds = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64;
+
+    civil_date(seconds).0 as u16
+}
+
+/// Formats `time` in the configured unit.
+pub fn format_time(time: Duration) -> String {
+    let micros = time.as_micros();
+    match config::get().time_unit {
+        config::TimeUnit::Seconds => format!("{:7.3} s", time.as_secs_f64()),
+        config::TimeUnit::Millis => format!("{:3}.{:03} ms", micros / 1000, micros % 1000),
+        config::TimeUnit::Micros => format!("{:7} us", micros),
+    }
+}
+
+// Threads running solutions get as much stack as the main thread, for recursive
+// solutions.
+const PART_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+fn timed(part: impl FnOnce() -> String) -> Answer {
+    let now = Instant::now();
+    let value = part();
+
+    Answer {
+        value,
+        time: now.elapsed(),
+    }
+}
+
+// Runs the parts given, at the same time when there are both and `jobs`
+// allows more than one thread, one after the other otherwise.
+fn run_parts(
+    part_1: Option<impl FnOnce() -> String>,
+    part_2: Option<impl FnOnce() -> String + Send>,
+) -> (Option<Answer>, Option<Answer>) {
+    match (part_1, part_2) {
+        (Some(part_1), Some(part_2)) if config::get().jobs > 1 => std::thread::scope(|scope| {
+            // Part 2 panics as quietly as part 1 would.
+            let quiet = QUIET.get();
+            let part_2 = std::thread::Builder::new()
+                .stack_size(PART_STACK_SIZE)
+                .spawn_scoped(scope, move || {
+                    QUIET.set(quiet);
+                    timed(part_2)
+                })
+                .expect("failed to start part 2");
+            let part_1 = timed(part_1);
+            let part_2 = part_2.join().unwrap_or_else(|e| panic::resume_unwind(e));
+
+            (Some(part_1), Some(part_2))
+        }),
+        (part_1, part_2) => (part_1.map(timed), part_2.map(timed)),
+    }
+}
 
 pub trait Solution {
-    type ParsedInput;
+    /// Shared by both parts, which may run at the same time.
+    type ParsedInput: Sync;
+
+    const METADATA: Metadata = Metadata::NONE;
 
     fn parse_input(input_lines: &str) -> Self::ParsedInput;
     fn part_1(parsed_input: &Self::ParsedInput) -> String;
-    fn part_2(parsed_input: Self::ParsedInput) -> String;
+    fn part_2(parsed_input: &Self::ParsedInput) -> String;
+
+    /// Parses the input as it is read. Line-oriented days override it so
+    /// huge inputs never have to fit in memory as a whole.
+    fn parse_reader(reader: &mut dyn BufRead) -> std::io::Result<Self::ParsedInput> {
+        let mut input_lines = String::new();
+        reader.read_to_string(&mut input_lines)?;
+
+        Ok(Self::parse_input(&input_lines))
+    }
+
+    /// Walkthrough of the first `limit` items of the input. Days without one
+    /// return nothing.
+    fn explain(_parsed_input: &Self::ParsedInput, _limit: usize) -> Vec<Explanation> {
+        vec![]
+    }
+
+    /// Picture of the puzzle for grid days.
+    fn render(_parsed_input: &Self::ParsedInput) -> Option<render::Grid> {
+        None
+    }
+
+    /// Checks of the properties of the input the solution relies on.
+    fn assumptions(_parsed_input: &Self::ParsedInput) -> Vec<Assumption> {
+        vec![]
+    }
+
+    /// Every intermediate state of simulation days.
+    fn frames(_parsed_input: Self::ParsedInput) -> Frames {
+        Box::new(std::iter::empty())
+    }
 
     fn solve_part_1(input_lines: &str) -> String {
         Self::part_1(&Self::parse_input(input_lines))
     }
     fn solve_part_2(input_lines: &str) -> String {
-        Self::part_2(Self::parse_input(input_lines))
+        Self::part_2(&Self::parse_input(input_lines))
     }
-    fn solve(input_lines: &str) -> (String, String) {
-        use std::time::Instant;
+    fn explain_input(input_lines: &str, limit: usize) -> Vec<Explanation> {
+        Self::explain(&Self::parse_input(input_lines), limit)
+    }
+    fn render_input(input_lines: &str) -> Option<render::Grid> {
+        Self::render(&Self::parse_input(input_lines))
+    }
+    fn assumptions_input(input_lines: &str) -> Vec<Assumption> {
+        Self::assumptions(&Self::parse_input(input_lines))
+    }
+    fn frames_input(input_lines: &str) -> Frames {
+        Self::frames(Self::parse_input(input_lines))
+    }
+    /// Only days whose parsed input is `Serialize` have it, so the `serde`
+    /// feature requires nothing of other implementations.
+    #[cfg(feature = "serde")]
+    fn parsed_json(input_lines: &str) -> serde_json::Result<Value>
+    where
+        Self::ParsedInput: serde::Serialize,
+    {
+        serde_json::to_value(Self::parse_input(input_lines))
+    }
+    fn run(input_lines: &str, part: Part) -> Run {
         let now = Instant::now();
         let input = Self::parse_input(input_lines);
-        let parse_time = now.elapsed().as_micros();
-        println!(
-            "Parsing: {:2}.{:03} ms",
-            parse_time / 1000,
-            parse_time & 1000
-        );
 
+        Self::run_parsed(input, now.elapsed(), part)
+    }
+    fn run_reader(reader: &mut dyn BufRead, part: Part) -> std::io::Result<Run> {
         let now = Instant::now();
-        let p1 = Self::part_1(&input);
-        let part1_time = now.elapsed().as_micros();
-        println!(
-            "Part 1: {:3}.{:03} ms -> {}",
-            part1_time / 1000,
-            part1_time & 1000,
-            p1
+        let input = Self::parse_reader(reader)?;
+
+        Ok(Self::run_parsed(input, now.elapsed(), part))
+    }
+    /// Runs the parts asked for on the parsed input, both at the same time
+    /// when `jobs` allows it.
+    fn run_parsed(input: Self::ParsedInput, parse_time: Duration, part: Part) -> Run {
+        let input = &input;
+        let (part_1, part_2) = run_parts(
+            part.includes(Part::One).then_some(|| Self::part_1(input)),
+            part.includes(Part::Two).then_some(|| Self::part_2(input)),
         );
 
-        let now = Instant::now();
-        let p2 = Self::part_2(input);
-        let part2_time = now.elapsed().as_micros();
-        println!(
-            "Part 2: {:3}.{:03} ms -> {}",
-            part2_time / 1000,
-            part2_time & 1000,
-            p2
+        Run {
+            parse_time,
+            part_1,
+            part_2,
+        }
+    }
+    fn solve(input_lines: &str) -> (String, String) {
+        let run = Self::run(input_lines, Part::Both);
+        run.print();
+        (run.part_1.unwrap().value, run.part_2.unwrap().value)
+    }
+}
+
+/// For days that solve their parts by working on the parsed input in place.
+/// They get `Solution` for free, part 1 working on a copy when part 2 needs
+/// the input too.
+pub trait SolutionMut {
+    type ParsedInput: Clone + Send + Sync;
+
+    const METADATA: Metadata = Metadata::NONE;
+
+    fn parse_input(input_lines: &str) -> Self::ParsedInput;
+    fn part_1(parsed_input: &mut Self::ParsedInput) -> String;
+    fn part_2(parsed_input: &mut Self::ParsedInput) -> String;
+
+    fn parse_reader(reader: &mut dyn BufRead) -> std::io::Result<Self::ParsedInput> {
+        let mut input_lines = String::new();
+        reader.read_to_string(&mut input_lines)?;
+
+        Ok(Self::parse_input(&input_lines))
+    }
+    fn explain(_parsed_input: &Self::ParsedInput, _limit: usize) -> Vec<Explanation> {
+        vec![]
+    }
+    fn render(_parsed_input: &Self::ParsedInput) -> Option<render::Grid> {
+        None
+    }
+    fn assumptions(_parsed_input: &Self::ParsedInput) -> Vec<Assumption> {
+        vec![]
+    }
+    fn frames(_parsed_input: Self::ParsedInput) -> Frames {
+        Box::new(std::iter::empty())
+    }
+}
+
+impl<T: SolutionMut> Solution for T {
+    type ParsedInput = T::ParsedInput;
+    const METADATA: Metadata = <T as SolutionMut>::METADATA;
+
+    fn parse_input(input_lines: &str) -> Self::ParsedInput {
+        <T as SolutionMut>::parse_input(input_lines)
+    }
+    fn part_1(parsed_input: &Self::ParsedInput) -> String {
+        <T as SolutionMut>::part_1(&mut parsed_input.clone())
+    }
+    fn part_2(parsed_input: &Self::ParsedInput) -> String {
+        <T as SolutionMut>::part_2(&mut parsed_input.clone())
+    }
+    fn parse_reader(reader: &mut dyn BufRead) -> std::io::Result<Self::ParsedInput> {
+        <T as SolutionMut>::parse_reader(reader)
+    }
+    fn run_parsed(input: Self::ParsedInput, parse_time: Duration, part: Part) -> Run {
+        let (input_1, input_2) = match part {
+            Part::One => (Some(input), None),
+            Part::Two => (None, Some(input)),
+            Part::Both => (Some(input.clone()), Some(input)),
+        };
+        let (part_1, part_2) = run_parts(
+            input_1.map(|mut input| move || <T as SolutionMut>::part_1(&mut input)),
+            input_2.map(|mut input| move || <T as SolutionMut>::part_2(&mut input)),
         );
-        (p1, p2)
+
+        Run {
+            parse_time,
+            part_1,
+            part_2,
+        }
+    }
+    fn explain(parsed_input: &Self::ParsedInput, limit: usize) -> Vec<Explanation> {
+        <T as SolutionMut>::explain(parsed_input, limit)
+    }
+    fn render(parsed_input: &Self::ParsedInput) -> Option<render::Grid> {
+        <T as SolutionMut>::render(parsed_input)
+    }
+    fn assumptions(parsed_input: &Self::ParsedInput) -> Vec<Assumption> {
+        <T as SolutionMut>::assumptions(parsed_input)
+    }
+    fn frames(parsed_input: Self::ParsedInput) -> Frames {
+        <T as SolutionMut>::frames(parsed_input)
     }
 }
 
-pub fn solve_day(year: u16, day: u8, input: u8) -> std::io::Result<()> {
-    let data_path = format!("data/aoc{}/day{}", year, day);
-    let mut input_str = String::new();
+/// Parses every line read from `reader`, for days that override
+/// `parse_reader`.
+pub fn parse_lines<T>(
+    reader: &mut dyn BufRead,
+    mut parse: impl FnMut(&str) -> T,
+) -> std::io::Result<Vec<T>> {
+    reader.lines().map(|line| Ok(parse(&line?))).collect()
+}
 
+/// Directory of the files of a day, failing when there is no data directory.
+pub fn data_path(year: u16, day: u8) -> std::io::Result<String> {
+    Ok(format!(
+        "{}/aoc{}/day{}",
+        config::get().data_dir()?.display(),
+        year,
+        day
+    ))
+}
+
+pub fn input_file_name(input: u8) -> &'static str {
     match input {
-        0 => File::open(format!("{}/input.txt", data_path).as_str())?
-            .read_to_string(&mut input_str)?,
-        1 => File::open(format!("{}/example1.txt", data_path).as_str())?
-            .read_to_string(&mut input_str)?,
-        2 => File::open(format!("{}/example2.txt", data_path).as_str())?
-            .read_to_string(&mut input_str)?,
+        0 => "input.txt",
+        1 => "example1.txt",
+        2 => "example2.txt",
         _ => panic!("Invalid input"),
-    };
+    }
+}
+
+pub fn read_input(year: u16, day: u8, input: u8) -> std::io::Result<String> {
+    read_day_file(year, day, input_file_name(input))
+}
 
-    match day {
-        3 => day3::Day3::solve(input_str.as_str()),
-        4 => day4::Day4::solve(input_str.as_str()),
-        5 => day5::Day5::solve(input_str.as_str()),
-        6 => day6::Day6::solve(input_str.as_str()),
-        7 => day7::Day7::solve(input_str.as_str()),
-        8 => day8::Day8::solve(input_str.as_str()),
-        9 => day9::Day9::solve(input_str.as_str()),
-        10 => day10::Day10::solve(input_str.as_str()),
-        11 => day11::Day11::solve(input_str.as_str()),
-        12 => day12::Day12::solve(input_str.as_str()),
-        13 => day13::Day13::solve(input_str.as_str()),
-        14 => day14::Day14::solve(input_str.as_str()),
-        15 => day15::Day15::solve(input_str.as_str()),
-        16 => day16::Day16::solve(input_str.as_str()),
-        17 => day17::Day17::solve(input_str.as_str()),
-        _ => (
-            String::from("Not implemented"),
-            String::from("Not implemented"),
+/// Text of `file` of a day, built into the binary or else from the data
+/// directory or its vault entry.
+pub fn read_day_file(year: u16, day: u8, file: &str) -> std::io::Result<String> {
+    #[cfg(feature = "embed-inputs")]
+    if let Some(text) = embedded::get(year, day, file) {
+        return Ok(text.to_string());
+    }
+    let path = format!("{}/{}", data_path(year, day)?, file);
+    vault::read(Path::new(&path)).map_err(|e| match e.kind() {
+        std::io::ErrorKind::NotFound => std::io::Error::new(e.kind(), format!("{}: {}", path, e)),
+        _ => e,
+    })
+}
+
+/// Whether `file` of a day is built into the binary, or in the data directory
+/// plain or encrypted.
+pub fn day_file_exists(year: u16, day: u8, file: &str) -> bool {
+    #[cfg(feature = "embed-inputs")]
+    if embedded::get(year, day, file).is_some() {
+        return true;
+    }
+
+    data_path(year, day).is_ok_and(|dir| vault::exists(&Path::new(&dir).join(file)))
+}
+
+/// Text of `file` when given, encrypted or not, or of the input number `input`
+/// of a day otherwise.
+pub fn read_input_or_file(
+    year: u16,
+    day: u8,
+    input: u8,
+    file: Option<&str>,
+) -> std::io::Result<String> {
+    match file {
+        Some(file) => vault::read(Path::new(file)),
+        None => read_input(year, day, input),
+    }
+}
+
+// Reader over a plain input file larger than the configured threshold.
+fn large_input(year: u16, day: u8, input: u8) -> std::io::Result<Option<BufReader<File>>> {
+    #[cfg(feature = "embed-inputs")]
+    if embedded::get(year, day, input_file_name(input)).is_some() {
+        return Ok(None);
+    }
+    let path = format!("{}/{}", data_path(year, day)?, input_file_name(input));
+    match std::fs::metadata(&path) {
+        Ok(metadata)
+            if metadata.len() > config::get().stream_threshold.saturating_mul(1024 * 1024) =>
+        {
+            Ok(Some(BufReader::new(File::open(path)?)))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Statement of a day, from `problem.md` or the older `problem.txt`.
+pub fn read_problem(year: u16, day: u8) -> std::io::Result<String> {
+    let path = data_path(year, day)?;
+    std::fs::read_to_string(format!("{}/problem.md", path))
+        .or_else(|_| std::fs::read_to_string(format!("{}/problem.txt", path)))
+        .map_err(|e| std::io::Error::new(e.kind(), format!("{}/problem.md: {}", path, e)))
+}
+
+/// Title of a day, taken from the `--- Day N: Title ---` heading of its statement.
+pub fn problem_title(year: u16, day: u8) -> Option<String> {
+    let problem = read_problem(year, day).ok()?;
+    let heading = problem.lines().find(|l| l.contains("--- Day"))?;
+    let (_, title) = heading.split_once(':')?;
+
+    Some(title.trim().trim_end_matches("---").trim().to_string())
+}
+
+/// Title of a day, from its metadata or else from its stored statement.
+/// Empty when neither has one.
+pub fn day_title(year: u16, day: u8) -> String {
+    match registry::find(year, day) {
+        Some(entry) if !entry.metadata.title.is_empty() => entry.metadata.title.to_string(),
+        _ => problem_title(year, day).unwrap_or_default(),
+    }
+}
+
+/// Accepted answers of both parts, one per line, from `path` or its vault
+/// entry. An empty line or `-` stands for an unknown answer.
+pub fn read_answers(path: &Path) -> std::io::Result<[Option<String>; 2]> {
+    Ok(parse_answers(&vault::read(path)?))
+}
+
+/// Answers of both parts out of the text of an answers file.
+pub fn parse_answers(answers: &str) -> [Option<String>; 2] {
+    let mut lines = answers
+        .lines()
+        .map(|l| l.trim())
+        .map(|l| (!l.is_empty() && l != "-").then(|| l.to_string()));
+
+    [lines.next().flatten(), lines.next().flatten()]
+}
+
+// Differences between recorded answers and the ones of a run. An unknown
+// answer on either side is not a difference.
+pub(crate) fn mismatches(expected: &[Option<String>; 2], got: &[Option<String>; 2]) -> Vec<String> {
+    (0..2)
+        .filter_map(|i| match (&expected[i], &got[i]) {
+            (Some(expected), Some(got)) if expected != got => Some(format!(
+                "part {}: expected {}, got {}",
+                i + 1,
+                expected,
+                got
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Checks that a grid has as many rows as columns, for days walking both
+/// axes with the same bound.
+pub(crate) fn square_grid<T>(statement: &'static str, grid: &[Vec<T>]) -> Assumption {
+    Assumption {
+        statement,
+        violation: grid
+            .iter()
+            .enumerate()
+            .find(|(_, row)| row.len() != grid.len())
+            .map(|(i, row)| {
+                format!(
+                    "{} rows, but row {} has {} columns",
+                    grid.len(),
+                    i + 1,
+                    row.len()
+                )
+            }),
+    }
+}
+
+/// Answers and timings of both parts of a day, as returned by `run`.
+#[derive(Debug, Clone)]
+pub struct RunResult {
+    pub parse_time: Duration,
+    pub part_1: Answer,
+    pub part_2: Answer,
+}
+
+/// Why `run` gave no answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// There is no built-in solution for the day.
+    NotImplemented { year: u16, day: u8 },
+    /// The solution panicked, usually `parse_input` choking on an unexpected
+    /// input. Holds the panic message.
+    Panicked(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NotImplemented { year, day } => {
+                write!(f, "{} day {} is not implemented", year, day)
+            }
+            Error::Panicked(message) => write!(f, "the solution panicked: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Solves both parts of a built-in day on `input` with its default
+/// implementation. Unlike `solve_day`, it reads no files, loads no plugins
+/// and prints nothing.
+pub fn run(year: u16, day: u8, input: &str) -> Result<RunResult, Error> {
+    let entry = registry::find(year, day).ok_or(Error::NotImplemented { year, day })?;
+    let run = catch_panic(|| (entry.run)(input, Part::Both)).map_err(Error::Panicked)?;
+
+    Ok(RunResult {
+        parse_time: run.parse_time,
+        part_1: run.part_1.unwrap_or_default(),
+        part_2: run.part_2.unwrap_or_default(),
+    })
+}
+
+/// Every built-in day, the ones `run` accepts, in order.
+pub fn days() -> Vec<(u16, u8)> {
+    let mut days: Vec<(u16, u8)> = registry::DAYS.iter().map(|e| (e.year, e.day)).collect();
+    days.sort();
+    days.dedup();
+
+    days
+}
+
+/// Runs a day from its plugin when one is loaded, or from the registry
+/// otherwise. Returns `None` when the day is not implemented.
+pub fn run_day(plugins: &Plugins, year: u16, day: u8, input: &str, part: Part) -> Option<Run> {
+    if let Some(plugin) = plugins.find(year, day) {
+        return Some(plugin.run(input, part));
+    }
+
+    registry::find(year, day).map(|entry| (entry.run)(input, part))
+}
+
+/// Like `run_day`, but a panicking solution, usually `parse_input` choking on
+/// an unexpected input, is returned as an error with the panic message.
+pub fn try_run_day(
+    plugins: &Plugins,
+    year: u16,
+    day: u8,
+    input: &str,
+    part: Part,
+) -> Result<Option<Run>, String> {
+    catch_panic(|| run_day(plugins, year, day, input, part))
+}
+
+thread_local! {
+    // Whether this thread runs inside `catch_panic`, so its panics go unprinted.
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+// Runs `f` without printing its panic, if any, returning the panic message instead.
+// The hook is wrapped once and never swapped back, and only silences the threads
+// running solutions, so the host program's own panics are still printed.
+pub(crate) fn catch_panic<T>(f: impl FnOnce() -> T + panic::UnwindSafe) -> Result<T, String> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.get() {
+                hook(info);
+            }
+        }));
+    });
+
+    let quiet = QUIET.replace(true);
+    let result = panic::catch_unwind(f);
+    QUIET.set(quiet);
+
+    result.map_err(panic_message)
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return message.to_string();
+    }
+    if let Some(message) = payload.downcast_ref::<String>() {
+        return message.clone();
+    }
+
+    String::from("panicked")
+}
+
+/// Every day that can be run, built-in or from a plugin.
+pub fn available_days(plugins: &Plugins) -> Vec<(u16, u8)> {
+    let mut days = days();
+    days.extend(plugins.days());
+    days.sort();
+    days.dedup();
+
+    days
+}
+
+// Plugins of the plugins directory, leaving out the ones that fail to load.
+pub(crate) fn load_plugins() -> Plugins {
+    let mut plugins = Plugins::new(plugin::PLUGINS_DIR);
+    for e in plugins.reload() {
+        eprintln!("Skipping plugin: {}", e);
+    }
+
+    plugins
+}
+
+/// Prints every day that can be run, or only those of `year` or tagged with
+/// `tag`, with its title, tags and where it comes from.
+pub fn list_days(year: Option<u16>, tag: Option<&str>) -> std::io::Result<()> {
+    let plugins = load_plugins();
+
+    for (y, d) in available_days(&plugins) {
+        // Plugins declare no metadata.
+        let tags = match (plugins.find(y, d), registry::find(y, d)) {
+            (None, Some(entry)) => entry.metadata.tags,
+            _ => &[],
+        };
+        if year.is_some_and(|year| year != y) || tag.is_some_and(|tag| !tags.contains(&tag)) {
+            continue;
+        }
+
+        let names: Vec<&str> = registry::implementations(y, d)
+            .iter()
+            .map(|e| e.name)
+            .collect();
+        let source = match (plugins.find(y, d), names.len()) {
+            (Some(_), _) => String::from("plugin"),
+            (None, 1) => String::new(),
+            (None, _) => names.join(", "),
+        };
+        let line = format!(
+            "{} day {:2}  {:32} {:40} {}",
+            y,
+            d,
+            day_title(y, d),
+            tags.join(", "),
+            source
+        );
+        println!("{}", line.trim_end());
+    }
+
+    Ok(())
+}
+
+/// Solves `part` of a day with its default implementation, or with the
+/// registered one called `implementation`.
+pub fn solve_day(
+    year: u16,
+    day: u8,
+    input: u8,
+    part: Part,
+    implementation: Option<&str>,
+) -> std::io::Result<()> {
+    let plugins = load_plugins();
+    progress::enable(std::io::stderr().is_terminal());
+
+    // Plugins are only given whole inputs.
+    let entry = match implementation {
+        Some(name) => Some(
+            registry::find_implementation(year, day, name).ok_or_else(|| {
+                let names: Vec<&str> = registry::implementations(year, day)
+                    .iter()
+                    .map(|e| e.name)
+                    .collect();
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!(
+                        "unknown implementation {}, expected one of: {}",
+                        name,
+                        names.join(", ")
+                    ),
+                )
+            })?,
         ),
+        None if plugins.find(year, day).is_some() => None,
+        None => {
+            Some(registry::find(year, day).ok_or_else(|| not_implemented(&plugins, year, day))?)
+        }
+    };
+
+    let run = match (entry, large_input(year, day, input)?) {
+        (Some(entry), Some(mut reader)) => (entry.run_reader)(&mut reader, part)?,
+        (Some(entry), None) => (entry.run)(&read_input(year, day, input)?, part),
+        (None, _) => run_day(&plugins, year, day, &read_input(year, day, input)?, part)
+            .ok_or_else(|| not_implemented(&plugins, year, day))?,
+    };
+
+    run.print();
+    results::record(year, day, input, &run)
+}
+
+pub(crate) fn not_implemented(plugins: &Plugins, year: u16, day: u8) -> std::io::Error {
+    let days: Vec<String> = available_days(plugins)
+        .iter()
+        .filter(|(y, _)| *y == year)
+        .map(|(_, d)| d.to_string())
+        .collect();
+    let message = if days.is_empty() {
+        format!("no day of {} is implemented", year)
+    } else {
+        format!(
+            "{} day {} is not implemented, available days: {}",
+            year,
+            day,
+            days.join(", ")
+        )
+    };
+
+    std::io::Error::new(std::io::ErrorKind::NotFound, message)
+}
+
+/// Runs every implementation of a day on the same input. Returns whether
+/// they all gave the same answers.
+pub fn crosscheck_day(year: u16, day: u8, input: u8) -> std::io::Result<bool> {
+    let entries = registry::implementations(year, day);
+    if entries.is_empty() {
+        return Err(not_implemented(&load_plugins(), year, day));
+    }
+    let input_str = read_input(year, day, input)?;
+
+    // Up to `jobs` implementations run at the same time, each with the stack
+    // recursive days need.
+    let mut runs: Vec<(&str, Run)> = vec![];
+    for chunk in entries.chunks(config::get().jobs) {
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = chunk
+                .iter()
+                .map(|e| {
+                    std::thread::Builder::new()
+                        .stack_size(PART_STACK_SIZE)
+                        .spawn_scoped(scope, || (e.name, (e.run)(input_str.as_str(), Part::Both)))
+                        .expect("failed to start an implementation")
+                })
+                .collect();
+            runs.extend(handles.into_iter().map(|h| h.join().unwrap()));
+        });
+    }
+
+    let answers =
+        |run: &Run| [&run.part_1, &run.part_2].map(|a| a.as_ref().map(|a| a.value.clone()));
+    let expected = answers(&runs[0].1);
+    let mut agree = true;
+    for (name, run) in runs.iter() {
+        println!("{}:", name);
+        run.print();
+        if answers(run) != expected {
+            agree = false;
+        }
+    }
+
+    if !agree {
+        println!();
+        println!("MISMATCH: implementations of {} day {} disagree", year, day);
+        for (name, run) in runs.iter() {
+            let [part_1, part_2] = answers(run);
+            println!(
+                "    {:12} part 1: {:20} part 2: {}",
+                name,
+                part_1.unwrap_or_default(),
+                part_2.unwrap_or_default()
+            );
+        }
+    }
+
+    Ok(agree)
+}
+
+pub fn explain_day(year: u16, day: u8, input: u8, limit: usize) -> std::io::Result<()> {
+    let input_str = read_input(year, day, input)?;
+
+    match registry::find(year, day) {
+        Some(entry) => {
+            let explanations = (entry.explain)(input_str.as_str(), limit);
+            if explanations.is_empty() {
+                println!("No walkthrough for this day");
+            }
+            explanations.iter().for_each(|e| e.print());
+        }
+        None => println!("Not implemented"),
+    };
+
+    Ok(())
+}
+
+pub fn render_day(year: u16, day: u8, input: u8, path: &str) -> std::io::Result<()> {
+    let input_str = read_input(year, day, input)?;
+
+    match registry::find(year, day).map(|entry| (entry.render)(input_str.as_str())) {
+        Some(Some(grid)) => {
+            grid.save(path)?;
+            println!("Rendered {}", path);
+        }
+        Some(None) => println!("This day is not a grid"),
+        None => println!("Not implemented"),
+    };
+
+    Ok(())
+}
+
+pub fn animate_day(year: u16, day: u8, input: u8) -> std::io::Result<()> {
+    let input_str = read_input(year, day, input)?;
+
+    match registry::find(year, day) {
+        Some(entry) => animation::play((entry.frames)(input_str.as_str()))?,
+        None => println!("Not implemented"),
     };
 
     Ok(())
@@ -1,29 +1,409 @@
 // #![feature(lazy_cell)]
 
-use std::env;
+use std::{io, process};
 
-use aoc23::solve_day;
+#[cfg(feature = "serde")]
+use aoc23::dump;
+use aoc23::{
+    animate_day, assumptions, bench,
+    config::{self, Config},
+    crosscheck_day, current_year, dashboard, explain_day, generality, leaderboard, list_days,
+    render_day, report, server, show, solve_day, vault, verify, Part, TAGS,
+};
+use clap::{builder::PossibleValuesParser, ArgGroup, Args, Parser, Subcommand};
+
+// The first year of Advent of Code, and the number of days of each year.
+const FIRST_YEAR: u16 = 2015;
+const DAYS: u8 = 25;
+
+#[derive(Parser)]
+#[command(version, about = "Advent of Code solutions")]
+struct Cli {
+    #[command(flatten)]
+    config: ConfigFlags,
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// Settings overriding the ones of aoc.toml.
+#[derive(Args)]
+#[command(next_help_heading = "Settings overriding aoc.toml")]
+struct ConfigFlags {
+    /// Directory of the inputs and statements
+    #[arg(long, global = true, value_name = "DIR")]
+    data_dir: Option<String>,
+    /// Directory of the results, benchmarks and downloads
+    #[arg(long, global = true, value_name = "DIR")]
+    cache_dir: Option<String>,
+    #[arg(long, global = true, value_parser = ["text", "json"])]
+    format: Option<String>,
+    #[arg(long, global = true, value_parser = ["s", "ms", "us"])]
+    time_unit: Option<String>,
+    #[arg(long, global = true, value_parser = ["auto", "always", "never"])]
+    colour: Option<String>,
+    /// Threads used to run several solutions at once
+    #[arg(long, global = true, value_name = "N")]
+    jobs: Option<String>,
+    /// Percentage a benchmark may get slower than its baseline
+    #[arg(long, global = true, value_name = "PERCENT")]
+    bench_threshold: Option<String>,
+    /// Size in MB above which inputs are parsed as they are read
+    #[arg(long, global = true, value_name = "MB")]
+    stream_threshold: Option<String>,
+    /// Read the data directory even when the inputs are built in
+    #[arg(long, global = true)]
+    on_disk: bool,
+}
+
+impl ConfigFlags {
+    fn apply(self, config: &mut Config) -> Result<(), String> {
+        let flags = [
+            ("data_dir", self.data_dir),
+            ("cache_dir", self.cache_dir),
+            ("format", self.format),
+            ("time_unit", self.time_unit),
+            ("colour", self.colour),
+            ("jobs", self.jobs),
+            ("bench_threshold", self.bench_threshold),
+            ("stream_threshold", self.stream_threshold),
+            ("on_disk", self.on_disk.then(|| String::from("true"))),
+        ];
+        for (key, value) in flags {
+            if let Some(value) = value {
+                config.set(key, &value)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day
+    Run(RunArgs),
+    /// Time every day, keep a history and flag regressions
+    Bench {
+        #[arg(value_parser = parse_year)]
+        year: Option<u16>,
+        #[arg(value_parser = parse_day)]
+        day: Option<u8>,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(0..=2))]
+        input: Option<u8>,
+        /// Runs of each day, the median is kept
+        #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u16).range(1..))]
+        runs: u16,
+        /// Fail when a part got slower than its recent runs
+        #[arg(long)]
+        compare: bool,
+    },
+    /// Check the answers of every day against its answers.txt
+    Verify {
+        #[arg(value_parser = parse_year)]
+        year: Option<u16>,
+        #[arg(value_parser = parse_day)]
+        day: Option<u8>,
+    },
+    /// List the days that can be run
+    List {
+        #[arg(value_parser = parse_year)]
+        year: Option<u16>,
+        /// Only list the days with this tag
+        #[arg(long, value_parser = PossibleValuesParser::new(TAGS))]
+        tag: Option<String>,
+    },
+    /// Read the stored puzzle statement of a day
+    Show {
+        #[arg(value_parser = parse_year)]
+        year: u16,
+        #[arg(value_parser = parse_day)]
+        day: u8,
+        /// Only show this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+    },
+    /// Browse and run every day in a terminal dashboard
+    Dashboard,
+    /// Solve inputs sent over HTTP on localhost
+    Serve {
+        #[arg(long, default_value_t = 8023)]
+        port: u16,
+    },
+    /// Analyse a saved private leaderboard, or fetch one with AOC_SESSION
+    #[command(group(ArgGroup::new("source").required(true).args(["file", "fetch"])))]
+    Leaderboard {
+        file: Option<String>,
+        #[arg(long, num_args = 2, value_names = ["YEAR", "ID"])]
+        fetch: Option<Vec<String>>,
+    },
+    /// Update the progress table of a markdown file, README.md by default
+    Report { file: Option<String> },
+    /// Run a day on everyone's inputs in data/aoc<year>/inputs/<owner>/
+    Generality {
+        #[arg(value_parser = parse_year)]
+        year: u16,
+        #[arg(value_parser = parse_day)]
+        day: u8,
+    },
+    /// Check that an input holds the assumptions its day relies on
+    #[command(allow_missing_positional = true)]
+    CheckInput {
+        /// Defaults to the year of aoc.toml
+        #[arg(value_parser = parse_year)]
+        year: Option<u16>,
+        #[arg(value_parser = parse_day)]
+        day: u8,
+        /// 0 for the full input, 1 and 2 for the examples
+        #[arg(long, value_parser = clap::value_parser!(u8).range(0..=2), conflicts_with = "file")]
+        input: Option<u8>,
+        /// Check this file instead of an input of the day
+        #[arg(long, value_name = "PATH")]
+        file: Option<String>,
+    },
+    /// Write the parsed input of a day as JSON
+    #[cfg(feature = "serde")]
+    #[command(allow_missing_positional = true)]
+    DumpParsed {
+        /// Defaults to the year of aoc.toml
+        #[arg(value_parser = parse_year)]
+        year: Option<u16>,
+        #[arg(value_parser = parse_day)]
+        day: u8,
+        /// 0 for the full input, 1 and 2 for the examples
+        #[arg(long, value_parser = clap::value_parser!(u8).range(0..=2), conflicts_with = "file")]
+        input: Option<u8>,
+        /// Parse this file instead of an input of the day
+        #[arg(long, value_name = "PATH")]
+        file: Option<String>,
+        /// Write to this file instead of stdout
+        #[arg(long, value_name = "FILE")]
+        output: Option<String>,
+    },
+    /// Encrypt inputs and answers so they can be committed
+    #[command(subcommand)]
+    Vault(VaultCommand),
+}
+
+#[derive(Args)]
+#[command(allow_missing_positional = true)]
+struct RunArgs {
+    /// Defaults to the year of aoc.toml
+    #[arg(value_parser = parse_year)]
+    year: Option<u16>,
+    #[arg(value_parser = parse_day)]
+    day: u8,
+    /// 0 for the full input, 1 and 2 for the examples
+    #[arg(long, value_parser = clap::value_parser!(u8).range(0..=2))]
+    input: Option<u8>,
+    /// 1, 2 or both
+    #[arg(long, default_value = "both", value_parser = parse_part, conflicts_with = "crosscheck")]
+    part: Part,
+    /// Solve with another implementation of the day
+    #[arg(long = "impl", value_name = "NAME", conflicts_with = "crosscheck")]
+    implementation: Option<String>,
+    /// Run every implementation and fail if their answers differ
+    #[arg(long)]
+    crosscheck: bool,
+    /// Walk through the first N items of the input
+    #[arg(long, value_name = "N", num_args = 0..=1, default_missing_value = "10")]
+    explain: Option<usize>,
+    /// Draw grid days to a .png or .svg file
+    #[arg(long, value_name = "FILE")]
+    render: Option<String>,
+    /// Replay simulation days step by step
+    #[arg(long)]
+    animate: bool,
+}
+
+#[derive(Subcommand)]
+enum VaultCommand {
+    /// Encrypt a file of a day
+    Add(VaultFile),
+    /// Decrypt a file of a day back to the plain file
+    Export(VaultFile),
+    /// Re-encrypt every entry with a new key
+    Rotate,
+}
+
+#[derive(Args)]
+struct VaultFile {
+    #[arg(value_parser = parse_year)]
+    year: u16,
+    #[arg(value_parser = parse_day)]
+    day: u8,
+    #[arg(default_value = "input.txt")]
+    file: String,
+}
+
+fn parse_year(arg: &str) -> Result<u16, String> {
+    match arg.parse() {
+        Ok(year) if year < FIRST_YEAR => Err(format!("Advent of Code started in {}", FIRST_YEAR)),
+        Ok(year) if year > current_year() => Err(format!("{} has no puzzles yet", year)),
+        Ok(year) => Ok(year),
+        Err(_) => Err(String::from("expected a year such as 2023")),
+    }
+}
+
+fn parse_day(arg: &str) -> Result<u8, String> {
+    match arg.parse() {
+        Ok(day) if (1..=DAYS).contains(&day) => Ok(day),
+        _ => Err(format!("expected a day from 1 to {}", DAYS)),
+    }
+}
+
+fn parse_part(arg: &str) -> Result<Part, String> {
+    match arg {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        "both" => Ok(Part::Both),
+        _ => Err(String::from("expected 1, 2 or both")),
+    }
+}
+
+// The year given on the command line, or else the one of aoc.toml.
+fn year_or_config(year: Option<u16>) -> io::Result<u16> {
+    year.or(config::get().year).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "no year given, and none set in aoc.toml",
+        )
+    })
+}
+
+fn run(args: RunArgs) -> io::Result<bool> {
+    let year = year_or_config(args.year)?;
+    let day = args.day;
+    let input = args.input.unwrap_or(config::get().input);
+
+    if args.crosscheck {
+        return crosscheck_day(year, day, input);
+    }
+    if let Some(limit) = args.explain {
+        explain_day(year, day, input, limit)?;
+        println!();
+    }
+    if args.animate {
+        animate_day(year, day, input)?;
+        println!();
+    }
+    if let Some(path) = args.render {
+        render_day(year, day, input, &path)?;
+        println!();
+    }
+
+    solve_day(year, day, input, args.part, args.implementation.as_deref())?;
+
+    Ok(true)
+}
+
+fn leaderboard(file: Option<String>, fetch: Option<Vec<String>>) -> io::Result<()> {
+    let board = match (file, fetch.as_deref()) {
+        (_, Some([year, id])) => {
+            let year =
+                parse_year(year).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+            leaderboard::Leaderboard::parse(&leaderboard::fetch(year, id)?)?
+        }
+        (Some(file), _) => leaderboard::load(&file)?,
+        _ => unreachable!("clap requires a file or --fetch"),
+    };
+    board.print();
+
+    Ok(())
+}
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 4 {
-        println!("Usage: {} <year 2019-2023> <day 1-25> <input 0-2>", args[0]);
-        println!("    year: the year you want to choose the day from");
-        println!("    number: the day you want to get the answer");
-        println!("    input:  the input you want to use");
-        println!("        - 0: full input");
-        println!("        - 1: example1 input");
-        println!("        - 2: example2 input");
-        return;
-    }
-
-    let year: u16 = args[1].parse().unwrap_or(0);
-    let number: u8 = args[2].parse().unwrap_or(0);
-    let input: u8 = args[3].parse().unwrap_or(0);
-    
-    match solve_day(year, number, input) {
-        Ok(_) => (),
-        Err(e) => println!("Error: {}", e),
+    let cli = Cli::parse();
+
+    let mut config = match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    };
+    if let Err(e) = cli.config.apply(&mut config) {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    }
+    config::init(config);
+
+    // Commands that fail without an error, like a wrong answer, return
+    // `Ok(false)` to exit with 1.
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Bench {
+            year,
+            day,
+            input,
+            runs,
+            compare,
+        } => bench::bench(
+            year.or(config::get().year),
+            day,
+            input.unwrap_or(config::get().input),
+            runs as usize,
+            compare,
+        ),
+        Command::Verify { year, day } => verify::verify(year.or(config::get().year), day),
+        Command::List { year, tag } => list_days(year, tag.as_deref()).map(|_| true),
+        Command::Show { year, day, part } => show::show(year, day, part).map(|_| true),
+        Command::Dashboard => dashboard::run().map(|_| true),
+        Command::Serve { port } => server::serve(port).map(|_| true),
+        Command::Leaderboard { file, fetch } => leaderboard(file, fetch).map(|_| true),
+        Command::Report { file } => {
+            let file = file.unwrap_or_else(|| {
+                config::project_root()
+                    .unwrap_or_default()
+                    .join("README.md")
+                    .display()
+                    .to_string()
+            });
+            report::write(&file).map(|_| true)
+        }
+        Command::Generality { year, day } => generality::check(year, day),
+        Command::CheckInput {
+            year,
+            day,
+            input,
+            file,
+        } => year_or_config(year).and_then(|year| {
+            assumptions::check_input(
+                year,
+                day,
+                input.unwrap_or(config::get().input),
+                file.as_deref(),
+            )
+        }),
+        #[cfg(feature = "serde")]
+        Command::DumpParsed {
+            year,
+            day,
+            input,
+            file,
+            output,
+        } => year_or_config(year).and_then(|year| {
+            dump::dump_parsed(
+                year,
+                day,
+                input.unwrap_or(config::get().input),
+                file.as_deref(),
+                output.as_deref(),
+            )
+        }),
+        Command::Vault(command) => match command {
+            VaultCommand::Add(f) => vault::add(f.year, f.day, &f.file),
+            VaultCommand::Export(f) => vault::export(f.year, f.day, &f.file),
+            VaultCommand::Rotate => vault::rotate(),
+        }
+        .map(|_| true),
+    };
+
+    match result {
+        Ok(true) => (),
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
     }
 }
@@ -0,0 +1,311 @@
+use std::{
+    ffi::{c_char, CStr, CString},
+    fs, io, panic,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, SystemTime},
+};
+
+use libloading::Library;
+
+use crate::{Answer, Part, Run, Solution};
+
+pub const PLUGINS_DIR: &str = "plugins";
+
+/// Bumped whenever any of the `extern "C"` types or functions below change.
+pub const ABI_VERSION: u32 = 1;
+
+#[repr(C)]
+pub struct FfiAnswer {
+    /// Null when the part was not run.
+    pub value: *mut c_char,
+    pub time_us: u64,
+}
+
+#[repr(C)]
+pub struct FfiRun {
+    /// False when the solution panicked.
+    pub ok: bool,
+    pub parse_us: u64,
+    pub part_1: FfiAnswer,
+    pub part_2: FfiAnswer,
+}
+
+type AbiVersionFn = extern "C" fn() -> u32;
+type DayFn = unsafe extern "C" fn(*mut u16, *mut u8);
+type RunFn = unsafe extern "C" fn(*const u8, usize, u8) -> FfiRun;
+type FreeFn = unsafe extern "C" fn(FfiRun);
+
+/// Exports `$solution` from a `cdylib` so the runner can load it from the
+/// plugins directory. The plugin crate depends on `aoc23` and only needs:
+///
+/// ```ignore
+/// aoc23::export_plugin!(2023, 4, aoc23::aoc2023::day4::Day4);
+/// ```
+#[macro_export]
+macro_rules! export_plugin {
+    ($year:expr, $day:expr, $solution:ty) => {
+        #[no_mangle]
+        pub extern "C" fn aoc_plugin_abi_version() -> u32 {
+            $crate::plugin::ABI_VERSION
+        }
+
+        #[no_mangle]
+        pub unsafe extern "C" fn aoc_plugin_day(year: *mut u16, day: *mut u8) {
+            *year = $year;
+            *day = $day;
+        }
+
+        #[no_mangle]
+        pub unsafe extern "C" fn aoc_plugin_run(
+            input: *const u8,
+            len: usize,
+            part: u8,
+        ) -> $crate::plugin::FfiRun {
+            $crate::plugin::export_run::<$solution>(input, len, part)
+        }
+
+        #[no_mangle]
+        pub unsafe extern "C" fn aoc_plugin_free(run: $crate::plugin::FfiRun) {
+            $crate::plugin::free_run(run)
+        }
+    };
+}
+
+fn part_to_ffi(part: Part) -> u8 {
+    match part {
+        Part::Both => 0,
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+fn part_from_ffi(part: u8) -> Part {
+    match part {
+        1 => Part::One,
+        2 => Part::Two,
+        _ => Part::Both,
+    }
+}
+
+fn answer_to_ffi(answer: Option<Answer>) -> FfiAnswer {
+    match answer {
+        Some(a) => FfiAnswer {
+            value: CString::new(a.value).unwrap_or_default().into_raw(),
+            time_us: a.time.as_micros() as u64,
+        },
+        None => FfiAnswer {
+            value: std::ptr::null_mut(),
+            time_us: 0,
+        },
+    }
+}
+
+unsafe fn answer_from_ffi(answer: &FfiAnswer) -> Option<Answer> {
+    if answer.value.is_null() {
+        return None;
+    }
+
+    Some(Answer {
+        value: CStr::from_ptr(answer.value).to_string_lossy().into_owned(),
+        time: Duration::from_micros(answer.time_us),
+    })
+}
+
+/// Plugin side of `aoc_plugin_run`, used by `export_plugin!`.
+///
+/// # Safety
+///
+/// `input` must point to `len` bytes of UTF-8 text.
+pub unsafe fn export_run<S: Solution>(input: *const u8, len: usize, part: u8) -> FfiRun {
+    let input = std::str::from_utf8_unchecked(std::slice::from_raw_parts(input, len));
+
+    // Unwinding across the FFI boundary would abort the runner.
+    match panic::catch_unwind(|| S::run(input, part_from_ffi(part))) {
+        Ok(run) => FfiRun {
+            ok: true,
+            parse_us: run.parse_time.as_micros() as u64,
+            part_1: answer_to_ffi(run.part_1),
+            part_2: answer_to_ffi(run.part_2),
+        },
+        Err(_) => FfiRun {
+            ok: false,
+            parse_us: 0,
+            part_1: answer_to_ffi(None),
+            part_2: answer_to_ffi(None),
+        },
+    }
+}
+
+/// Plugin side of `aoc_plugin_free`, used by `export_plugin!`.
+///
+/// # Safety
+///
+/// `run` must have been returned by `export_run` in the same library.
+pub unsafe fn free_run(run: FfiRun) {
+    for value in [run.part_1.value, run.part_2.value] {
+        if !value.is_null() {
+            drop(CString::from_raw(value));
+        }
+    }
+}
+
+pub struct Plugin {
+    pub year: u16,
+    pub day: u8,
+    path: PathBuf,
+    modified: SystemTime,
+    run: RunFn,
+    free: FreeFn,
+    // Keeps `run` and `free` valid, so it must be dropped last.
+    _library: Library,
+}
+
+impl Plugin {
+    fn load(path: &Path) -> io::Result<Self> {
+        static LOADED: AtomicUsize = AtomicUsize::new(0);
+
+        let modified = fs::metadata(path)?.modified()?;
+
+        // The dynamic loader caches libraries by path, so a rebuilt plugin is
+        // loaded from a fresh copy to pick up the new code.
+        let copy = std::env::temp_dir().join(format!(
+            "aoc23-{}-{}-{}",
+            std::process::id(),
+            LOADED.fetch_add(1, Ordering::Relaxed),
+            path.file_name().unwrap().to_string_lossy()
+        ));
+        fs::copy(path, &copy)?;
+        let library = unsafe { Library::new(&copy) };
+        let _ = fs::remove_file(&copy);
+        let library = library.map_err(io::Error::other)?;
+
+        unsafe {
+            let abi_version = library
+                .get::<AbiVersionFn>(b"aoc_plugin_abi_version")
+                .map_err(io::Error::other)?();
+            if abi_version != ABI_VERSION {
+                return Err(io::Error::other(format!(
+                    "ABI version {} (expected {})",
+                    abi_version, ABI_VERSION
+                )));
+            }
+
+            let (mut year, mut day) = (0, 0);
+            library
+                .get::<DayFn>(b"aoc_plugin_day")
+                .map_err(io::Error::other)?(&mut year, &mut day);
+
+            let run = *library
+                .get::<RunFn>(b"aoc_plugin_run")
+                .map_err(io::Error::other)?;
+            let free = *library
+                .get::<FreeFn>(b"aoc_plugin_free")
+                .map_err(io::Error::other)?;
+
+            Ok(Self {
+                year,
+                day,
+                path: path.to_path_buf(),
+                modified,
+                run,
+                free,
+                _library: library,
+            })
+        }
+    }
+
+    /// Runs the plugin's solution, panicking if the solution panicked inside
+    /// the plugin, the same way a built-in day would.
+    pub fn run(&self, input: &str, part: Part) -> Run {
+        let run = unsafe {
+            let ffi = (self.run)(input.as_ptr(), input.len(), part_to_ffi(part));
+            let run = if ffi.ok {
+                Some(Run {
+                    parse_time: Duration::from_micros(ffi.parse_us),
+                    part_1: answer_from_ffi(&ffi.part_1),
+                    part_2: answer_from_ffi(&ffi.part_2),
+                })
+            } else {
+                None
+            };
+            (self.free)(ffi);
+
+            run
+        };
+
+        run.unwrap_or_else(|| panic!("{} panicked", self.path.display()))
+    }
+}
+
+fn is_library(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|e| e.to_str()),
+        Some("so" | "dylib" | "dll")
+    )
+}
+
+/// Every plugin found in a directory.
+pub struct Plugins {
+    dir: PathBuf,
+    loaded: Vec<Plugin>,
+}
+
+impl Plugins {
+    pub fn new(dir: &str) -> Self {
+        Self {
+            dir: PathBuf::from(dir),
+            loaded: vec![],
+        }
+    }
+
+    /// Loads new plugins, reloads the ones whose file changed and drops the
+    /// ones that were removed. A missing directory means no plugins.
+    ///
+    /// A plugin that fails to load is skipped, or keeps its previous version
+    /// when it had one, and its error is returned with the others.
+    pub fn reload(&mut self) -> Vec<io::Error> {
+        let paths: Vec<PathBuf> = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries
+                .filter_map(|e| e.ok().map(|e| e.path()))
+                .filter(|p| is_library(p))
+                .collect(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => vec![],
+            Err(e) => return vec![e],
+        };
+
+        self.loaded.retain(|p| paths.contains(&p.path));
+
+        let mut errors = vec![];
+        for path in paths.iter() {
+            let index = self.loaded.iter().position(|p| p.path == *path);
+            let changed = fs::metadata(path)
+                .and_then(|m| m.modified())
+                .map(|modified| index.is_none_or(|i| self.loaded[i].modified != modified));
+            let loaded = match changed {
+                Ok(false) => continue,
+                Ok(true) => Plugin::load(path),
+                Err(e) => Err(e),
+            };
+
+            match (loaded, index) {
+                (Ok(plugin), Some(i)) => self.loaded[i] = plugin,
+                (Ok(plugin), None) => self.loaded.push(plugin),
+                (Err(e), _) => errors.push(io::Error::new(
+                    e.kind(),
+                    format!("{}: {}", path.display(), e),
+                )),
+            }
+        }
+
+        errors
+    }
+
+    pub fn find(&self, year: u16, day: u8) -> Option<&Plugin> {
+        self.loaded.iter().find(|p| p.year == year && p.day == day)
+    }
+
+    pub fn days(&self) -> Vec<(u16, u8)> {
+        self.loaded.iter().map(|p| (p.year, p.day)).collect()
+    }
+}
@@ -0,0 +1,133 @@
+use std::{
+    io::{self, Write},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+// Solutions report progress through these functions without knowing who, if
+// anyone, is watching. Nothing is drawn unless the runner called `enable`, so
+// a disabled report costs a single atomic load.
+
+const BAR_WIDTH: usize = 30;
+const REDRAW_EVERY: Duration = Duration::from_millis(100);
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static DONE: AtomicU64 = AtomicU64::new(0);
+static TASK: Mutex<Option<Task>> = Mutex::new(None);
+
+struct Task {
+    label: String,
+    total: u64,
+    best: Option<String>,
+    started: Instant,
+    drawn: Option<Instant>,
+}
+
+impl Task {
+    fn draw(&mut self, done: u64, force: bool) {
+        let now = Instant::now();
+        if !force && self.drawn.is_some_and(|d| now - d < REDRAW_EVERY) {
+            return;
+        }
+        self.drawn = Some(now);
+
+        let filled = if self.total == 0 {
+            0
+        } else {
+            (done.min(self.total) as f64 / self.total as f64 * BAR_WIDTH as f64) as usize
+        };
+        let rate = done as f64 / (now - self.started).as_secs_f64().max(f64::EPSILON);
+        let best = match &self.best {
+            Some(best) => format!(" best: {}", best),
+            None => String::new(),
+        };
+
+        let mut stderr = io::stderr();
+        let _ = write!(
+            stderr,
+            "\r\x1b[2K{} [{}{}] {}/{} {:.0}/s{}",
+            self.label,
+            "#".repeat(filled),
+            "-".repeat(BAR_WIDTH - filled),
+            done,
+            self.total,
+            rate,
+            best
+        );
+        let _ = stderr.flush();
+    }
+}
+
+/// Turns rendering on or off. The runner enables it on interactive terminals.
+pub fn enable(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+fn with_task(force: bool, update: impl FnOnce(&mut Task)) {
+    if let Some(task) = TASK.lock().unwrap().as_mut() {
+        update(task);
+        task.draw(DONE.load(Ordering::Relaxed), force);
+    }
+}
+
+/// Starts a task of `total` items, replacing any unfinished one.
+pub fn start(label: &str, total: u64) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    DONE.store(0, Ordering::Relaxed);
+    *TASK.lock().unwrap() = Some(Task {
+        label: label.to_string(),
+        total,
+        best: None,
+        started: Instant::now(),
+        drawn: None,
+    });
+    with_task(true, |_| {});
+}
+
+/// Marks `n` more items as done.
+pub fn advance(n: u64) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    DONE.fetch_add(n, Ordering::Relaxed);
+    with_task(false, |_| {});
+}
+
+/// Sets the number of items done so far.
+pub fn set(done: u64) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    DONE.store(done, Ordering::Relaxed);
+    with_task(false, |_| {});
+}
+
+/// Reports the best value found so far.
+pub fn best(value: impl ToString) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    with_task(false, |task| task.best = Some(value.to_string()));
+}
+
+/// Ends the current task and clears its line.
+pub fn finish() {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    if TASK.lock().unwrap().take().is_some() {
+        let mut stderr = io::stderr();
+        let _ = write!(stderr, "\r\x1b[2K");
+        let _ = stderr.flush();
+    }
+}
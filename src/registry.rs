@@ -0,0 +1,281 @@
+use std::io::{self, BufRead};
+
+use crate::aoc2023::*;
+use crate::{
+    animation::Frames, render::Grid, Assumption, Explanation, Metadata, Part, Run, Solution,
+};
+
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    /// Several entries may share a day; the first one is the default.
+    pub name: &'static str,
+    pub metadata: Metadata,
+    pub run: fn(&str, Part) -> Run,
+    /// Same as `run`, parsing the input as it is read.
+    pub run_reader: fn(&mut dyn BufRead, Part) -> io::Result<Run>,
+    pub explain: fn(&str, usize) -> Vec<Explanation>,
+    pub render: fn(&str) -> Option<Grid>,
+    pub assumptions: fn(&str) -> Vec<Assumption>,
+    pub frames: fn(&str) -> Frames,
+    #[cfg(feature = "serde")]
+    pub parsed_json: fn(&str) -> serde_json::Result<serde_json::Value>,
+}
+
+pub const DAYS: [Entry; 17] = [
+    Entry {
+        year: 2023,
+        day: 3,
+        name: "default",
+        metadata: day3::Day3::METADATA,
+        run: day3::Day3::run,
+        run_reader: day3::Day3::run_reader,
+        explain: day3::Day3::explain_input,
+        render: day3::Day3::render_input,
+        assumptions: day3::Day3::assumptions_input,
+        frames: day3::Day3::frames_input,
+        #[cfg(feature = "serde")]
+        parsed_json: day3::Day3::parsed_json,
+    },
+    Entry {
+        year: 2023,
+        day: 4,
+        name: "default",
+        metadata: day4::Day4::METADATA,
+        run: day4::Day4::run,
+        run_reader: day4::Day4::run_reader,
+        explain: day4::Day4::explain_input,
+        render: day4::Day4::render_input,
+        assumptions: day4::Day4::assumptions_input,
+        frames: day4::Day4::frames_input,
+        #[cfg(feature = "serde")]
+        parsed_json: day4::Day4::parsed_json,
+    },
+    Entry {
+        year: 2023,
+        day: 5,
+        name: "stepping",
+        metadata: day5::Day5::METADATA,
+        run: day5::Day5::run,
+        run_reader: day5::Day5::run_reader,
+        explain: day5::Day5::explain_input,
+        render: day5::Day5::render_input,
+        assumptions: day5::Day5::assumptions_input,
+        frames: day5::Day5::frames_input,
+        #[cfg(feature = "serde")]
+        parsed_json: day5::Day5::parsed_json,
+    },
+    Entry {
+        year: 2023,
+        day: 5,
+        name: "intervals",
+        metadata: day5::Day5Intervals::METADATA,
+        run: day5::Day5Intervals::run,
+        run_reader: day5::Day5Intervals::run_reader,
+        explain: day5::Day5::explain_input,
+        render: day5::Day5::render_input,
+        assumptions: day5::Day5::assumptions_input,
+        frames: day5::Day5::frames_input,
+        #[cfg(feature = "serde")]
+        parsed_json: day5::Day5::parsed_json,
+    },
+    Entry {
+        year: 2023,
+        day: 6,
+        name: "default",
+        metadata: day6::Day6::METADATA,
+        run: day6::Day6::run,
+        run_reader: day6::Day6::run_reader,
+        explain: day6::Day6::explain_input,
+        render: day6::Day6::render_input,
+        assumptions: day6::Day6::assumptions_input,
+        frames: day6::Day6::frames_input,
+        #[cfg(feature = "serde")]
+        parsed_json: day6::Day6::parsed_json,
+    },
+    Entry {
+        year: 2023,
+        day: 7,
+        name: "default",
+        metadata: day7::Day7::METADATA,
+        run: day7::Day7::run,
+        run_reader: day7::Day7::run_reader,
+        explain: day7::Day7::explain_input,
+        render: day7::Day7::render_input,
+        assumptions: day7::Day7::assumptions_input,
+        frames: day7::Day7::frames_input,
+        #[cfg(feature = "serde")]
+        parsed_json: day7::Day7::parsed_json,
+    },
+    Entry {
+        year: 2023,
+        day: 8,
+        name: "default",
+        metadata: day8::Day8::METADATA,
+        run: day8::Day8::run,
+        run_reader: day8::Day8::run_reader,
+        explain: day8::Day8::explain_input,
+        render: day8::Day8::render_input,
+        assumptions: day8::Day8::assumptions_input,
+        frames: day8::Day8::frames_input,
+        #[cfg(feature = "serde")]
+        parsed_json: day8::Day8::parsed_json,
+    },
+    Entry {
+        year: 2023,
+        day: 9,
+        name: "default",
+        metadata: day9::Day9::METADATA,
+        run: day9::Day9::run,
+        run_reader: day9::Day9::run_reader,
+        explain: day9::Day9::explain_input,
+        render: day9::Day9::render_input,
+        assumptions: day9::Day9::assumptions_input,
+        frames: day9::Day9::frames_input,
+        #[cfg(feature = "serde")]
+        parsed_json: day9::Day9::parsed_json,
+    },
+    Entry {
+        year: 2023,
+        day: 10,
+        name: "scanline",
+        metadata: day10::Day10::METADATA,
+        run: day10::Day10::run,
+        run_reader: day10::Day10::run_reader,
+        explain: day10::Day10::explain_input,
+        render: day10::Day10::render_input,
+        assumptions: day10::Day10::assumptions_input,
+        frames: day10::Day10::frames_input,
+        #[cfg(feature = "serde")]
+        parsed_json: day10::Day10::parsed_json,
+    },
+    Entry {
+        year: 2023,
+        day: 10,
+        name: "shoelace",
+        metadata: day10::Day10Shoelace::METADATA,
+        run: day10::Day10Shoelace::run,
+        run_reader: day10::Day10Shoelace::run_reader,
+        explain: day10::Day10::explain_input,
+        render: day10::Day10::render_input,
+        assumptions: day10::Day10::assumptions_input,
+        frames: day10::Day10::frames_input,
+        #[cfg(feature = "serde")]
+        parsed_json: day10::Day10::parsed_json,
+    },
+    Entry {
+        year: 2023,
+        day: 11,
+        name: "default",
+        metadata: day11::Day11::METADATA,
+        run: day11::Day11::run,
+        run_reader: day11::Day11::run_reader,
+        explain: day11::Day11::explain_input,
+        render: day11::Day11::render_input,
+        assumptions: day11::Day11::assumptions_input,
+        frames: day11::Day11::frames_input,
+        #[cfg(feature = "serde")]
+        parsed_json: day11::Day11::parsed_json,
+    },
+    Entry {
+        year: 2023,
+        day: 12,
+        name: "default",
+        metadata: day12::Day12::METADATA,
+        run: day12::Day12::run,
+        run_reader: day12::Day12::run_reader,
+        explain: day12::Day12::explain_input,
+        render: day12::Day12::render_input,
+        assumptions: day12::Day12::assumptions_input,
+        frames: day12::Day12::frames_input,
+        #[cfg(feature = "serde")]
+        parsed_json: day12::Day12::parsed_json,
+    },
+    Entry {
+        year: 2023,
+        day: 13,
+        name: "default",
+        metadata: day13::Day13::METADATA,
+        run: day13::Day13::run,
+        run_reader: day13::Day13::run_reader,
+        explain: day13::Day13::explain_input,
+        render: day13::Day13::render_input,
+        assumptions: day13::Day13::assumptions_input,
+        frames: day13::Day13::frames_input,
+        #[cfg(feature = "serde")]
+        parsed_json: day13::Day13::parsed_json,
+    },
+    Entry {
+        year: 2023,
+        day: 14,
+        name: "default",
+        metadata: day14::Day14::METADATA,
+        run: day14::Day14::run,
+        run_reader: day14::Day14::run_reader,
+        explain: day14::Day14::explain_input,
+        render: day14::Day14::render_input,
+        assumptions: day14::Day14::assumptions_input,
+        frames: day14::Day14::frames_input,
+        #[cfg(feature = "serde")]
+        parsed_json: day14::Day14::parsed_json,
+    },
+    Entry {
+        year: 2023,
+        day: 15,
+        name: "default",
+        metadata: day15::Day15::METADATA,
+        run: day15::Day15::run,
+        run_reader: day15::Day15::run_reader,
+        explain: day15::Day15::explain_input,
+        render: day15::Day15::render_input,
+        assumptions: day15::Day15::assumptions_input,
+        frames: day15::Day15::frames_input,
+        #[cfg(feature = "serde")]
+        parsed_json: day15::Day15::parsed_json,
+    },
+    Entry {
+        year: 2023,
+        day: 16,
+        name: "default",
+        metadata: day16::Day16::METADATA,
+        run: day16::Day16::run,
+        run_reader: day16::Day16::run_reader,
+        explain: day16::Day16::explain_input,
+        render: day16::Day16::render_input,
+        assumptions: day16::Day16::assumptions_input,
+        frames: day16::Day16::frames_input,
+        #[cfg(feature = "serde")]
+        parsed_json: day16::Day16::parsed_json,
+    },
+    Entry {
+        year: 2023,
+        day: 17,
+        name: "default",
+        metadata: day17::Day17::METADATA,
+        run: day17::Day17::run,
+        run_reader: day17::Day17::run_reader,
+        explain: day17::Day17::explain_input,
+        render: day17::Day17::render_input,
+        assumptions: day17::Day17::assumptions_input,
+        frames: day17::Day17::frames_input,
+        #[cfg(feature = "serde")]
+        parsed_json: day17::Day17::parsed_json,
+    },
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Entry> {
+    DAYS.iter().find(|e| e.year == year && e.day == day)
+}
+
+/// Every implementation of a day, default first.
+pub fn implementations(year: u16, day: u8) -> Vec<&'static Entry> {
+    DAYS.iter()
+        .filter(|e| e.year == year && e.day == day)
+        .collect()
+}
+
+pub fn find_implementation(year: u16, day: u8, name: &str) -> Option<&'static Entry> {
+    implementations(year, day)
+        .into_iter()
+        .find(|e| e.name == name)
+}
@@ -0,0 +1,210 @@
+use std::{
+    collections::HashSet,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+// Pixels per cell in PNG output
+const CELL: usize = 8;
+
+type Rgb = [u8; 3];
+
+const WALL: Rgb = [60, 60, 60];
+const EMPTY: Rgb = [235, 235, 235];
+const OTHER: Rgb = [160, 160, 160];
+const HIGHLIGHT: Rgb = [250, 190, 20];
+const PATH: Rgb = [30, 200, 60];
+
+/// A 2D puzzle grid plus what to draw on top of it.
+#[derive(Debug, Clone)]
+pub struct Grid {
+    cells: Vec<Vec<char>>,
+    highlights: Vec<(usize, usize)>,
+    path: Vec<(usize, usize)>,
+    heat: Option<Vec<Vec<usize>>>,
+}
+
+impl Grid {
+    pub fn new(cells: Vec<Vec<char>>) -> Self {
+        Self {
+            cells,
+            highlights: vec![],
+            path: vec![],
+            heat: None,
+        }
+    }
+
+    /// Cells to highlight, as (row, column).
+    pub fn with_highlights(mut self, highlights: Vec<(usize, usize)>) -> Self {
+        self.highlights = highlights;
+        self
+    }
+
+    /// Cells of a path, in order, as (row, column).
+    pub fn with_path(mut self, path: Vec<(usize, usize)>) -> Self {
+        self.path = path;
+        self
+    }
+
+    /// One value per cell, coloured from blue (lowest) to red (highest).
+    pub fn with_heat(mut self, heat: Vec<Vec<usize>>) -> Self {
+        self.heat = Some(heat);
+        self
+    }
+
+    fn rows(&self) -> usize {
+        self.cells.len()
+    }
+
+    fn columns(&self) -> usize {
+        self.cells.iter().map(|r| r.len()).max().unwrap_or(0)
+    }
+
+    pub(crate) fn cells(&self) -> &Vec<Vec<char>> {
+        &self.cells
+    }
+
+    // Colour of every cell: path over highlights over heat over the cell itself.
+    pub(crate) fn colours(&self) -> Vec<Vec<Rgb>> {
+        let path: HashSet<&(usize, usize)> = self.path.iter().collect();
+        let highlights: HashSet<&(usize, usize)> = self.highlights.iter().collect();
+        let heat = self.heat.as_ref().map(|heat| {
+            let min = heat.iter().flatten().min().copied().unwrap_or(0);
+            let max = heat.iter().flatten().max().copied().unwrap_or(0);
+            (heat, min, max)
+        });
+
+        (0..self.rows())
+            .map(|row| {
+                (0..self.columns())
+                    .map(|column| {
+                        if path.contains(&(row, column)) {
+                            return PATH;
+                        }
+                        if highlights.contains(&(row, column)) {
+                            return HIGHLIGHT;
+                        }
+                        if let Some((heat, min, max)) = heat {
+                            if let Some(value) = heat.get(row).and_then(|r| r.get(column)) {
+                                let t = if max == min {
+                                    0.0
+                                } else {
+                                    (value - min) as f64 / (max - min) as f64
+                                };
+                                return [(255.0 * t) as u8, 40, (255.0 * (1.0 - t)) as u8];
+                            }
+                        }
+
+                        match self.cells[row].get(column) {
+                            Some('#') => WALL,
+                            Some('.') | None => EMPTY,
+                            _ => OTHER,
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn write_png(&self, path: &Path) -> io::Result<()> {
+        let (width, height) = (self.columns() * CELL, self.rows() * CELL);
+
+        let mut pixels = vec![0; width * height * 3];
+        for (row, colours) in self.colours().iter().enumerate() {
+            for (column, colour) in colours.iter().enumerate() {
+                for y in row * CELL..(row + 1) * CELL {
+                    for x in column * CELL..(column + 1) * CELL {
+                        let i = (y * width + x) * 3;
+                        pixels[i..i + 3].copy_from_slice(colour);
+                    }
+                }
+            }
+        }
+
+        let mut encoder = png::Encoder::new(
+            BufWriter::new(File::create(path)?),
+            width as u32,
+            height as u32,
+        );
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut w| w.write_image_data(&pixels))
+            .map_err(io::Error::other)
+    }
+
+    fn write_svg(&self, path: &Path) -> io::Result<()> {
+        const SIZE: usize = 16;
+        let mut f = BufWriter::new(File::create(path)?);
+
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" font-family="monospace" font-size="{}" text-anchor="middle">"#,
+            self.columns() * SIZE,
+            self.rows() * SIZE,
+            SIZE - 4
+        )?;
+
+        for (row, colours) in self.colours().iter().enumerate() {
+            for (column, [r, g, b]) in colours.iter().enumerate() {
+                writeln!(
+                    f,
+                    r#"<rect x="{}" y="{}" width="{SIZE}" height="{SIZE}" fill="rgb({r},{g},{b})"/>"#,
+                    column * SIZE,
+                    row * SIZE
+                )?;
+
+                let ch = self.cells[row].get(column).copied().unwrap_or('.');
+                if ch != '.' {
+                    let text = match ch {
+                        '<' => "&lt;".to_string(),
+                        '>' => "&gt;".to_string(),
+                        '&' => "&amp;".to_string(),
+                        _ => ch.to_string(),
+                    };
+                    writeln!(
+                        f,
+                        r#"<text x="{}" y="{}">{}</text>"#,
+                        column * SIZE + SIZE / 2,
+                        row * SIZE + SIZE - 4,
+                        text
+                    )?;
+                }
+            }
+        }
+
+        if !self.path.is_empty() {
+            let points = self
+                .path
+                .iter()
+                .map(|(row, column)| {
+                    format!("{},{}", column * SIZE + SIZE / 2, row * SIZE + SIZE / 2)
+                })
+                .collect::<Vec<String>>()
+                .join(" ");
+            writeln!(
+                f,
+                r#"<polyline points="{}" fill="none" stroke="black" stroke-width="2"/>"#,
+                points
+            )?;
+        }
+
+        writeln!(f, "</svg>")?;
+        f.flush()
+    }
+
+    /// Writes the grid as PNG or SVG, depending on the extension of `path`.
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        let path = Path::new(path);
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("png") => self.write_png(path),
+            Some("svg") => self.write_svg(path),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{}: expected a .png or .svg file", path.display()),
+            )),
+        }
+    }
+}
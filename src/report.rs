@@ -0,0 +1,101 @@
+use std::{fs, io, time::Duration};
+
+use crate::{day_title, format_time, parse_answers, read_day_file, registry, results, Answer};
+
+// `report` rewrites the part of the README between these two lines, or adds
+// them at the end when they are missing.
+pub const START: &str = "<!-- report:start -->";
+pub const END: &str = "<!-- report:end -->";
+
+fn time(answer: &Option<Answer>) -> String {
+    match answer {
+        Some(answer) => format_time(answer.time).trim().to_string(),
+        None => String::from("-"),
+    }
+}
+
+/// Markdown table of every built-in day, with the times of its last run on the
+/// full input.
+pub fn table() -> String {
+    let mut table = String::from(
+        "| Year | Day | Title | Approach | Stars | Part 1 | Part 2 | Total |\n\
+         |------|----:|-------|----------|-------|-------:|-------:|------:|\n",
+    );
+
+    let mut total_stars = 0;
+    let mut total_time = Duration::ZERO;
+    for entry in registry::DAYS.iter() {
+        if registry::find(entry.year, entry.day).map(|e| e.name) != Some(entry.name) {
+            continue;
+        }
+
+        let run = results::last(entry.year, entry.day, 0).unwrap_or_default();
+        let answers = [&run.part_1, &run.part_2];
+        // Only answers matching the accepted ones in answers.txt earn a star.
+        let expected = read_day_file(entry.year, entry.day, "answers.txt")
+            .map(|text| parse_answers(&text))
+            .unwrap_or_default();
+        let stars = answers
+            .iter()
+            .zip(expected.iter())
+            .filter(|(got, expected)| match (got, expected) {
+                (Some(got), Some(expected)) => !got.value.is_empty() && got.value == *expected,
+                _ => false,
+            })
+            .count();
+        let time_taken: Duration =
+            run.parse_time + answers.iter().flat_map(|a| a.iter()).map(|a| a.time).sum();
+        total_stars += stars;
+        total_time += time_taken;
+
+        table.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} | {} | {} |\n",
+            entry.year,
+            entry.day,
+            day_title(entry.year, entry.day),
+            entry.metadata.algorithm,
+            "⭐".repeat(stars),
+            time(&run.part_1),
+            time(&run.part_2),
+            // Printed whenever the part times are, so the column adds up to
+            // the total.
+            if answers.iter().any(|a| a.is_some()) {
+                format_time(time_taken).trim().to_string()
+            } else {
+                String::from("-")
+            }
+        ));
+    }
+
+    table.push_str(&format!(
+        "| | | **Total** | | {} | | | {} |\n",
+        total_stars,
+        format_time(total_time).trim()
+    ));
+
+    table
+}
+
+/// Replaces the report section of the markdown file at `path` with a new table.
+pub fn write(path: &str) -> io::Result<()> {
+    let text = fs::read_to_string(path)?;
+    let section = format!("{}\n{}{}", START, table(), END);
+
+    let text = match (text.find(START), text.find(END)) {
+        (Some(start), Some(end)) if start < end => {
+            format!("{}{}{}", &text[..start], section, &text[end + END.len()..])
+        }
+        (None, None) => format!("{}\n## Progress\n\n{}\n", text.trim_end(), section),
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: expected {} followed by {}", path, START, END),
+            ))
+        }
+    };
+
+    fs::write(path, text)?;
+    println!("Updated {}", path);
+
+    Ok(())
+}
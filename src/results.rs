@@ -0,0 +1,108 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::{config, Answer, Run};
+
+// One line per input: input, parse time, part 1 time, part 1, part 2 time, part 2.
+// Times are in microseconds and a part that was never run is written as "-".
+fn results_path(year: u16, day: u8) -> String {
+    format!(
+        "{}/aoc{}/day{}/results.txt",
+        config::get().cache_dir(),
+        year,
+        day
+    )
+}
+
+fn parse_answer(time: &str, value: &str) -> Option<Answer> {
+    if value == "-" {
+        return None;
+    }
+
+    Some(Answer {
+        value: value.to_string(),
+        time: Duration::from_micros(time.parse().ok()?),
+    })
+}
+
+fn parse_line(line: &str) -> Option<(u8, Run)> {
+    let fields: Vec<&str> = line.split('\t').collect();
+    if fields.len() != 6 {
+        return None;
+    }
+
+    Some((
+        fields[0].parse().ok()?,
+        Run {
+            parse_time: Duration::from_micros(fields[1].parse().ok()?),
+            part_1: parse_answer(fields[2], fields[3]),
+            part_2: parse_answer(fields[4], fields[5]),
+        },
+    ))
+}
+
+fn format_answer(answer: &Option<Answer>) -> String {
+    match answer {
+        Some(a) => format!("{}\t{}", a.time.as_micros(), a.value),
+        None => "-\t-".to_string(),
+    }
+}
+
+fn format_line(input: u8, run: &Run) -> String {
+    format!(
+        "{}\t{}\t{}\t{}\n",
+        input,
+        run.parse_time.as_micros(),
+        format_answer(&run.part_1),
+        format_answer(&run.part_2)
+    )
+}
+
+fn read_all(year: u16, day: u8) -> Vec<(u8, Run)> {
+    fs::read_to_string(results_path(year, day))
+        .unwrap_or_default()
+        .lines()
+        .filter_map(parse_line)
+        .collect()
+}
+
+/// Last recorded run of `input`, if the day was ever run with it.
+pub fn last(year: u16, day: u8, input: u8) -> Option<Run> {
+    read_all(year, day)
+        .into_iter()
+        .find(|(i, _)| *i == input)
+        .map(|(_, run)| run)
+}
+
+/// Stores `run` as the last run of `input`. Parts that were not run keep their
+/// previously recorded answer.
+pub fn record(year: u16, day: u8, input: u8, run: &Run) -> io::Result<()> {
+    let mut runs = read_all(year, day);
+
+    match runs.iter_mut().find(|(i, _)| *i == input) {
+        Some((_, last)) => {
+            last.parse_time = run.parse_time;
+            if run.part_1.is_some() {
+                last.part_1 = run.part_1.clone();
+            }
+            if run.part_2.is_some() {
+                last.part_2 = run.part_2.clone();
+            }
+        }
+        None => runs.push((input, run.clone())),
+    }
+    runs.sort_by_key(|(i, _)| *i);
+
+    let path = results_path(year, day);
+    if let Some(dir) = Path::new(&path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(
+        path,
+        runs.iter()
+            .map(|(i, r)| format_line(*i, r))
+            .collect::<String>(),
+    )
+}
@@ -0,0 +1,95 @@
+use std::io;
+
+use serde_json::{json, Value};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::{
+    available_days,
+    plugin::{Plugins, PLUGINS_DIR},
+    try_run_day, Part,
+};
+
+// Routes:
+//   GET  /days               every available day
+//   GET  /days/<year>        available days of a year
+//   POST /solve/<year>/<day> runs the day on the raw input sent as the body
+
+fn days_json(plugins: &Plugins, year: Option<u16>) -> Value {
+    available_days(plugins)
+        .iter()
+        .filter(|(y, _)| year.is_none() || year == Some(*y))
+        .map(|(year, day)| json!({ "year": year, "day": day }))
+        .collect()
+}
+
+fn solve_json(plugins: &Plugins, year: u16, day: u8, input: &str) -> (u16, Value) {
+    match try_run_day(plugins, year, day, input, Part::Both) {
+        Ok(Some(run)) => {
+            let mut value = run.to_json();
+            value["year"] = json!(year);
+            value["day"] = json!(day);
+            value["error"] = Value::Null;
+            (200, value)
+        }
+        Ok(None) => (
+            404,
+            json!({ "year": year, "day": day, "error": "not implemented" }),
+        ),
+        Err(e) => (422, json!({ "year": year, "day": day, "error": e })),
+    }
+}
+
+fn route(plugins: &Plugins, request: &mut Request) -> (u16, Value) {
+    let url = request.url().to_string();
+    let segments: Vec<&str> = url
+        .trim_start_matches('/')
+        .trim_end_matches('/')
+        .split('/')
+        .collect();
+
+    match (request.method(), segments.as_slice()) {
+        (Method::Get, ["days"]) => (200, days_json(plugins, None)),
+        (Method::Get, ["days", year]) => match year.parse() {
+            Ok(year) => (200, days_json(plugins, Some(year))),
+            Err(_) => (400, json!({ "error": format!("invalid year {}", year) })),
+        },
+        (Method::Post, ["solve", year, day]) => {
+            let (Ok(year), Ok(day)) = (year.parse(), day.parse()) else {
+                return (400, json!({ "error": "invalid year or day" }));
+            };
+
+            let mut input = String::new();
+            if let Err(e) = request.as_reader().read_to_string(&mut input) {
+                return (400, json!({ "error": e.to_string() }));
+            }
+
+            solve_json(plugins, year, day, &input)
+        }
+        _ => (404, json!({ "error": format!("no route for {}", url) })),
+    }
+}
+
+/// Serves the solutions over HTTP on localhost until the process is killed.
+pub fn serve(port: u16) -> io::Result<()> {
+    let server = Server::http(("127.0.0.1", port)).map_err(io::Error::other)?;
+    let mut plugins = Plugins::new(PLUGINS_DIR);
+    println!("Listening on http://127.0.0.1:{}", port);
+
+    for mut request in server.incoming_requests() {
+        // A plugin that fails to load must not take the server down with it.
+        for e in plugins.reload() {
+            println!("Error: {}", e);
+        }
+        let (status, body) = route(&plugins, &mut request);
+        println!("{} {} -> {}", request.method(), request.url(), status);
+
+        let response = Response::from_string(body.to_string())
+            .with_status_code(status)
+            .with_header(Header::from_bytes("Content-Type", "application/json").unwrap());
+        if let Err(e) = request.respond(response) {
+            println!("Error: {}", e);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,200 @@
+use std::{
+    env,
+    io::{self, IsTerminal, Write},
+    process::{Command, Stdio},
+};
+
+use pulldown_cmark::{CodeBlockKind, Event, Parser, Tag};
+use ratatui::crossterm::terminal;
+
+use crate::{config, read_problem};
+
+const BOLD: &str = "\x1b[1m";
+const ITALIC: &str = "\x1b[3m";
+const UNDERLINE: &str = "\x1b[4m";
+const HEADING: &str = "\x1b[1;33m";
+const CODE: &str = "\x1b[36m";
+const RESET: &str = "\x1b[0m";
+
+const PART_TWO: &str = "--- Part Two ---";
+
+// Statements are html2text output: paragraphs are already wrapped, so line
+// breaks are kept as they are.
+struct Renderer {
+    out: String,
+    colour: bool,
+    // Styles currently open, reapplied after each reset
+    styles: Vec<&'static str>,
+    lists: Vec<Option<u64>>,
+    in_code_block: bool,
+}
+
+impl Renderer {
+    fn push_style(&mut self, style: &'static str) {
+        self.styles.push(style);
+        if self.colour {
+            self.out.push_str(style);
+        }
+    }
+
+    fn pop_style(&mut self) {
+        self.styles.pop();
+        if self.colour {
+            self.out.push_str(RESET);
+            self.out.push_str(&self.styles.concat());
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if !self.in_code_block {
+            self.out.push_str(text);
+            return;
+        }
+
+        for line in text.lines() {
+            self.out.push_str("    ");
+            self.out.push_str(line);
+            self.out.push('\n');
+        }
+    }
+
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(Tag::Heading(..)) => self.push_style(HEADING),
+            Event::End(Tag::Heading(..)) => {
+                self.pop_style();
+                self.out.push_str("\n\n");
+            }
+            Event::End(Tag::Paragraph) => self.out.push_str("\n\n"),
+            Event::Start(Tag::Emphasis) => self.push_style(ITALIC),
+            Event::Start(Tag::Strong) => self.push_style(BOLD),
+            Event::Start(Tag::Link(..)) => self.push_style(UNDERLINE),
+            Event::End(Tag::Emphasis | Tag::Strong | Tag::Link(..)) => self.pop_style(),
+            Event::Start(Tag::CodeBlock(kind)) => {
+                if let CodeBlockKind::Fenced(_) = kind {
+                    self.out.push('\n');
+                }
+                self.in_code_block = true;
+                self.push_style(CODE);
+            }
+            Event::End(Tag::CodeBlock(_)) => {
+                self.in_code_block = false;
+                self.pop_style();
+                self.out.push('\n');
+            }
+            Event::Start(Tag::List(start)) => self.lists.push(start),
+            Event::End(Tag::List(_)) => {
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.out.push('\n');
+                }
+            }
+            Event::Start(Tag::Item) => {
+                let indent = "  ".repeat(self.lists.len());
+                let bullet = match self.lists.last_mut() {
+                    Some(Some(n)) => {
+                        *n += 1;
+                        format!("{}.", *n - 1)
+                    }
+                    _ => String::from("-"),
+                };
+                self.out.push_str(&format!("{}{} ", indent, bullet));
+            }
+            Event::End(Tag::Item) => self.out.push('\n'),
+            Event::Code(code) => {
+                self.push_style(CODE);
+                // Highlighted code comes out of html2text as `_8_`.
+                match code.strip_prefix('_').and_then(|c| c.strip_suffix('_')) {
+                    Some(emphasised) if !emphasised.is_empty() => {
+                        self.push_style(BOLD);
+                        self.out.push_str(emphasised);
+                        self.pop_style();
+                    }
+                    _ => self.out.push_str(&code),
+                }
+                self.pop_style();
+            }
+            Event::Text(text) => self.text(&text),
+            Event::SoftBreak | Event::HardBreak => self.out.push('\n'),
+            Event::Rule => self.out.push_str("---\n\n"),
+            _ => {}
+        }
+    }
+}
+
+/// `problem` with markdown styling turned into terminal escapes, or stripped
+/// when `colour` is off.
+pub fn render(problem: &str, colour: bool) -> String {
+    let mut renderer = Renderer {
+        out: String::new(),
+        colour,
+        styles: vec![],
+        lists: vec![],
+        in_code_block: false,
+    };
+    Parser::new(problem).for_each(|e| renderer.event(e));
+
+    renderer.out.trim_end().to_string() + "\n"
+}
+
+/// Only `part` of a statement: 1 for up to the Part Two heading, 2 from it.
+fn select_part(problem: &str, part: Option<u8>) -> &str {
+    let split = problem.match_indices('\n').map(|(i, _)| i + 1).find(|i| {
+        problem[*i..]
+            .lines()
+            .next()
+            .is_some_and(|l| l.contains(PART_TWO))
+    });
+
+    match (part, split) {
+        (Some(1), Some(split)) => &problem[..split],
+        (Some(2), Some(split)) => &problem[split..],
+        (Some(2), None) => "",
+        _ => problem,
+    }
+}
+
+// Sends `text` through `$PAGER` (less by default) when it does not fit on the
+// terminal, or prints it otherwise.
+fn page(text: &str) -> io::Result<()> {
+    let rows = terminal::size().map_or(24, |(_, rows)| rows as usize);
+
+    if !io::stdout().is_terminal() || text.lines().count() < rows {
+        print!("{}", text);
+        return Ok(());
+    }
+
+    let pager = env::var("PAGER").unwrap_or_else(|_| String::from("less -R"));
+    let mut words = pager.split_whitespace();
+    let spawned = words.next().map(|program| {
+        Command::new(program)
+            .args(words)
+            .stdin(Stdio::piped())
+            .spawn()
+    });
+
+    match spawned {
+        Some(Ok(mut child)) => {
+            if let Some(mut stdin) = child.stdin.take() {
+                // The pager may be closed before reading everything.
+                let _ = stdin.write_all(text.as_bytes());
+            }
+            child.wait()?;
+        }
+        _ => print!("{}", text),
+    }
+
+    Ok(())
+}
+
+/// Shows the stored statement of a day, or only one of its parts.
+pub fn show(year: u16, day: u8, part: Option<u8>) -> io::Result<()> {
+    let problem = read_problem(year, day)?;
+    let selected = select_part(&problem, part);
+    if selected.trim().is_empty() {
+        println!("No part {} stored for this day", part.unwrap_or(2));
+        return Ok(());
+    }
+
+    page(&render(selected, config::get().use_colour()))
+}
@@ -0,0 +1,240 @@
+use std::{
+    env, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    ChaCha20Poly1305, Key, Nonce,
+};
+
+use crate::{config, data_path};
+
+// Inputs and answers are committed as `<file>.enc` next to where the plain file
+// would be, encrypted with a key that never leaves the machine: the hex string
+// in `AOC_VAULT_KEY`, or `~/.config/aoc23/vault.key`. Each entry is a magic
+// header, a random nonce and the ChaCha20-Poly1305 ciphertext.
+
+pub const KEY_VAR: &str = "AOC_VAULT_KEY";
+pub const EXTENSION: &str = "enc";
+
+const MAGIC: &[u8] = b"AOCV1";
+const NONCE_LEN: usize = 12;
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    let hex = hex.trim();
+    if hex.len() % 2 == 1 {
+        return None;
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+fn key_file() -> io::Result<PathBuf> {
+    config::user_dir()
+        .map(|dir| dir.join("vault.key"))
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "No home directory for vault.key"))
+}
+
+fn parse_key(hex: &str, source: &str) -> io::Result<Key> {
+    match from_hex(hex) {
+        Some(bytes) if bytes.len() == 32 => Ok(*Key::from_slice(&bytes)),
+        _ => Err(invalid(format!("{}: expected 64 hex characters", source))),
+    }
+}
+
+fn load_key() -> io::Result<Key> {
+    if let Ok(hex) = env::var(KEY_VAR) {
+        return parse_key(&hex, KEY_VAR);
+    }
+
+    let path = key_file()?;
+    match fs::read_to_string(&path) {
+        Ok(hex) => parse_key(&hex, &path.display().to_string()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "No vault key: set {} or create one with `vault add`",
+                KEY_VAR
+            ),
+        )),
+        Err(e) => Err(e),
+    }
+}
+
+fn save_key(key: &Key, path: &Path) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    // The key is never readable by others, not even before it is written, and
+    // an existing key file is never overwritten.
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    options.open(path)?.write_all(to_hex(key).as_bytes())
+}
+
+// The key in use, or a new one saved to the key file when there is none yet.
+fn load_or_create_key() -> io::Result<Key> {
+    match load_key() {
+        Err(e) if e.kind() == io::ErrorKind::NotFound && env::var(KEY_VAR).is_err() => {
+            let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+            let path = key_file()?;
+            save_key(&key, &path)?;
+            println!("Created a new vault key in {}", path.display());
+            Ok(key)
+        }
+        key => key,
+    }
+}
+
+fn encrypt(key: &Key, plain: &[u8]) -> io::Result<Vec<u8>> {
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let cipher = ChaCha20Poly1305::new(key)
+        .encrypt(&nonce, plain)
+        .map_err(|_| invalid(String::from("encryption failed")))?;
+
+    Ok([MAGIC, nonce.as_slice(), &cipher].concat())
+}
+
+fn decrypt(key: &Key, entry: &[u8], path: &Path) -> io::Result<Vec<u8>> {
+    let Some(rest) = entry.strip_prefix(MAGIC).filter(|r| r.len() >= NONCE_LEN) else {
+        return Err(invalid(format!("{}: not a vault entry", path.display())));
+    };
+
+    let (nonce, cipher) = rest.split_at(NONCE_LEN);
+    ChaCha20Poly1305::new(key)
+        .decrypt(Nonce::from_slice(nonce), cipher)
+        .map_err(|_| invalid(format!("{}: wrong key or corrupted entry", path.display())))
+}
+
+/// Whether `path` exists as a plain file or as a vault entry.
+pub fn exists(path: &Path) -> bool {
+    path.is_file() || entry_path(path).is_file()
+}
+
+fn entry_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{}", EXTENSION));
+    PathBuf::from(name)
+}
+
+fn day_file(year: u16, day: u8, file: &str) -> io::Result<PathBuf> {
+    Ok(Path::new(&data_path(year, day)?).join(file))
+}
+
+/// Contents of `path`, decrypted from its vault entry when only that exists.
+pub fn read(path: &Path) -> io::Result<String> {
+    match fs::read_to_string(path) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound && entry_path(path).is_file() => {
+            let entry = entry_path(path);
+            let plain = decrypt(&load_key()?, &fs::read(&entry)?, &entry)?;
+            String::from_utf8(plain).map_err(|e| invalid(format!("{}: {}", entry.display(), e)))
+        }
+        read => read,
+    }
+}
+
+/// Encrypts `file` of a day (`input.txt`, `answers.txt`...) into the vault.
+pub fn add(year: u16, day: u8, file: &str) -> io::Result<()> {
+    let path = day_file(year, day, file)?;
+    let plain = fs::read(&path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+    let entry = entry_path(&path);
+
+    fs::write(&entry, encrypt(&load_or_create_key()?, &plain)?)?;
+    println!("Added {}", entry.display());
+
+    Ok(())
+}
+
+/// Decrypts the vault entry of `file` of a day back to the plain file.
+pub fn export(year: u16, day: u8, file: &str) -> io::Result<()> {
+    let path = day_file(year, day, file)?;
+    let entry = entry_path(&path);
+    let plain = decrypt(&load_key()?, &fs::read(&entry)?, &entry)?;
+
+    fs::write(&path, plain)?;
+    println!("Exported {}", path.display());
+
+    Ok(())
+}
+
+fn entries(dir: &Path, found: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            entries(&path, found)?;
+        } else if path.extension().is_some_and(|e| e == EXTENSION) {
+            found.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+/// Re-encrypts every entry of the data directory with a new key, then
+/// replaces the key file.
+pub fn rotate() -> io::Result<()> {
+    if env::var(KEY_VAR).is_ok() {
+        return Err(io::Error::other(format!(
+            "Unset {} to rotate the key in the key file",
+            KEY_VAR
+        )));
+    }
+
+    let old = load_key()?;
+    let mut found = vec![];
+    entries(config::get().data_dir()?, &mut found)?;
+
+    // Decrypt everything first, so a bad entry leaves the vault untouched.
+    let plains = found
+        .iter()
+        .map(|path| decrypt(&old, &fs::read(path)?, path))
+        .collect::<io::Result<Vec<Vec<u8>>>>()?;
+
+    let new = ChaCha20Poly1305::generate_key(&mut OsRng);
+    let path = key_file()?;
+    // Kept aside until every entry is rewritten, in case rotating stops halfway.
+    let new_path = path.with_extension("key.new");
+    save_key(&new, &new_path).map_err(|e| match e.kind() {
+        io::ErrorKind::AlreadyExists => io::Error::new(
+            e.kind(),
+            format!(
+                "{} is left from a rotation that stopped halfway: some entries may use it",
+                new_path.display()
+            ),
+        ),
+        _ => e,
+    })?;
+    for (path, plain) in found.iter().zip(plains) {
+        fs::write(path, encrypt(&new, &plain)?)?;
+    }
+
+    fs::rename(new_path, &path)?;
+    println!(
+        "Rotated {} entries, new key in {}",
+        found.len(),
+        path.display()
+    );
+
+    Ok(())
+}
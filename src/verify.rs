@@ -0,0 +1,106 @@
+use std::io;
+
+use crate::{
+    catch_panic, day_file_exists, day_title, format_time, input_file_name, mismatches,
+    parse_answers, read_day_file, read_input, registry, Part,
+};
+
+// Accepted answers of a day are kept next to its input in `answers.txt`, part 1
+// on the first line and part 2 on the second.
+
+/// Runs every built-in day of `year` (or just `day`) on its full input and
+/// compares the answers with the accepted ones. Returns whether none differed.
+pub fn verify(year: Option<u16>, day: Option<u8>) -> io::Result<bool> {
+    let mut verified = 0;
+    let mut failed = 0;
+    let mut unchecked = 0;
+    for entry in registry::DAYS.iter() {
+        let first = registry::find(entry.year, entry.day).map(|e| e.name);
+        if year.is_some_and(|y| y != entry.year)
+            || day.is_some_and(|d| d != entry.day)
+            || first != Some(entry.name)
+        {
+            continue;
+        }
+        // Only a day without its input is skipped: an input that can't be
+        // read, like a vault entry without its key, is a failure.
+        if !day_file_exists(entry.year, entry.day, input_file_name(0)) {
+            continue;
+        }
+        let name = format!(
+            "{} day {:2}  {:32}",
+            entry.year,
+            entry.day,
+            day_title(entry.year, entry.day)
+        );
+        let input = match read_input(entry.year, entry.day, 0) {
+            Ok(input) => input,
+            Err(e) => {
+                failed += 1;
+                println!("{}  ERROR  {}", name, e);
+                continue;
+            }
+        };
+
+        let run = match catch_panic(|| (entry.run)(&input, Part::Both)) {
+            Ok(run) => run,
+            Err(e) => {
+                failed += 1;
+                println!("{}  ERROR  panicked: {}", name, e);
+                continue;
+            }
+        };
+        let time = format_time(
+            run.parse_time
+                + run
+                    .part_1
+                    .iter()
+                    .chain(run.part_2.iter())
+                    .map(|a| a.time)
+                    .sum(),
+        );
+        let got = [&run.part_1, &run.part_2].map(|a| a.as_ref().map(|a| a.value.clone()));
+
+        let expected = match read_day_file(entry.year, entry.day, "answers.txt") {
+            Ok(answers) => parse_answers(&answers),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                let got = got.map(|g| g.unwrap_or_default());
+                println!(
+                    "{}  ?      {}  no answers.txt, got part 1: {}  part 2: {}",
+                    name, time, got[0], got[1]
+                );
+                unchecked += 1;
+                continue;
+            }
+            Err(e) => {
+                failed += 1;
+                println!("{}  ERROR  {}", name, e);
+                continue;
+            }
+        };
+
+        let mismatches = mismatches(&expected, &got);
+        if mismatches.is_empty() {
+            verified += 1;
+            println!("{}  pass   {}", name, time);
+        } else {
+            failed += 1;
+            println!("{}  FAIL   {}", name, time);
+            mismatches.iter().for_each(|m| println!("    {}", m));
+        }
+    }
+
+    if verified + failed + unchecked == 0 {
+        println!("Nothing to verify: no day has a full input");
+    } else {
+        println!();
+        println!(
+            "{}/{} days verified, {} without answers",
+            verified,
+            verified + failed,
+            unchecked
+        );
+    }
+
+    Ok(failed == 0)
+}